@@ -0,0 +1,238 @@
+use anyhow::Result;
+use chrono::NaiveDate;
+use ratatui::{
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Clear, Padding, Paragraph},
+    Frame,
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use wordle_tui::wordle_core::{Game, GameStatus, LetterState};
+
+use crate::{config::Config, help, stats::Stats, storage};
+
+const QUICK_SOLVE: Duration = Duration::from_mins(1);
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Achievement {
+    FirstWin,
+    HoleInOne,
+    TwoForTwo,
+    Clutch,
+    NoYellows,
+    DoubleLetters,
+    HardWon,
+    Quick,
+    WeekStreak,
+    MonthStreak,
+    Centurion,
+}
+
+impl Achievement {
+    pub const ALL: [Self; 11] = [
+        Self::FirstWin,
+        Self::HoleInOne,
+        Self::TwoForTwo,
+        Self::Clutch,
+        Self::NoYellows,
+        Self::DoubleLetters,
+        Self::HardWon,
+        Self::Quick,
+        Self::WeekStreak,
+        Self::MonthStreak,
+        Self::Centurion,
+    ];
+
+    // written to the achievements file, so these can't change
+    const fn id(self) -> &'static str {
+        match self {
+            Self::FirstWin => "first-win",
+            Self::HoleInOne => "hole-in-one",
+            Self::TwoForTwo => "two-for-two",
+            Self::Clutch => "clutch",
+            Self::NoYellows => "no-yellows",
+            Self::DoubleLetters => "double-letters",
+            Self::HardWon => "hard-won",
+            Self::Quick => "quick",
+            Self::WeekStreak => "week-streak",
+            Self::MonthStreak => "month-streak",
+            Self::Centurion => "centurion",
+        }
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::FirstWin => "off the mark",
+            Self::HoleInOne => "hole in one",
+            Self::TwoForTwo => "two for two",
+            Self::Clutch => "clutch",
+            Self::NoYellows => "straight to green",
+            Self::DoubleLetters => "seeing double",
+            Self::HardWon => "hard won",
+            Self::Quick => "quick draw",
+            Self::WeekStreak => "week streak",
+            Self::MonthStreak => "month streak",
+            Self::Centurion => "centurion",
+        }
+    }
+
+    const fn description(self) -> &'static str {
+        match self {
+            Self::FirstWin => "win a game",
+            Self::HoleInOne => "solve it with the first guess",
+            Self::TwoForTwo => "solve it in two guesses",
+            Self::Clutch => "solve it with the last guess",
+            Self::NoYellows => "win without a single yellow",
+            Self::DoubleLetters => "solve a word with a repeated letter",
+            Self::HardWon => "win in hard mode",
+            Self::Quick => "solve it in under a minute",
+            Self::WeekStreak => "win 7 daily puzzles in a row",
+            Self::MonthStreak => "win 30 daily puzzles in a row",
+            Self::Centurion => "play 100 daily puzzles",
+        }
+    }
+
+    // `stats` already include this game if it was a daily puzzle
+    fn earned(self, game: &Game, elapsed: Duration, stats: &Stats, today: NaiveDate) -> bool {
+        let won = game.status() == GameStatus::Won;
+        let guesses = game.guesses().len();
+
+        match self {
+            Self::FirstWin => won,
+            Self::HoleInOne => won && guesses == 1,
+            Self::TwoForTwo => won && guesses <= 2,
+            Self::Clutch => won && guesses == game.max_guesses(),
+            Self::NoYellows => {
+                won && game
+                    .guesses()
+                    .iter()
+                    .all(|guess| !guess.states.contains(&LetterState::Present))
+            }
+            Self::DoubleLetters => {
+                let solution = game.solution();
+                won && solution
+                    .chars()
+                    .any(|letter| solution.matches(letter).count() > 1)
+            }
+            Self::HardWon => won && game.hard_mode(),
+            Self::Quick => won && !elapsed.is_zero() && elapsed < QUICK_SOLVE,
+            Self::WeekStreak => stats.current_streak(today) >= 7,
+            Self::MonthStreak => stats.current_streak(today) >= 30,
+            Self::Centurion => stats.played() >= 100,
+        }
+    }
+}
+
+// every achievement unlocked so far and the day it happened, kept next to the stats
+#[derive(Debug, Default)]
+pub struct Achievements {
+    path: PathBuf,
+    unlocked: Vec<(Achievement, NaiveDate)>,
+}
+
+impl Achievements {
+    // <id> <date unlocked>
+    pub fn load(path: &Path) -> Self {
+        let unlocked = fs::read_to_string(path)
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| {
+                let (id, date) = line.split_once(' ')?;
+                let achievement = Achievement::ALL.into_iter().find(|a| a.id() == id)?;
+                Some((
+                    achievement,
+                    NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?,
+                ))
+            })
+            .collect();
+
+        Self {
+            path: path.to_path_buf(),
+            unlocked,
+        }
+    }
+
+    fn is_unlocked(&self, achievement: Achievement) -> bool {
+        self.unlocked.iter().any(|&(a, _)| a == achievement)
+    }
+
+    // unlocks and saves whatever this game earned that wasn't already, returning just those
+    pub fn unlock(
+        &mut self,
+        game: &Game,
+        elapsed: Duration,
+        stats: &Stats,
+        today: NaiveDate,
+    ) -> Result<Vec<Achievement>> {
+        let new = Achievement::ALL
+            .into_iter()
+            .filter(|&achievement| !self.is_unlocked(achievement))
+            .filter(|achievement| achievement.earned(game, elapsed, stats, today))
+            .collect::<Vec<Achievement>>();
+        if new.is_empty() {
+            return Ok(new);
+        }
+
+        self.unlocked
+            .extend(new.iter().map(|&achievement| (achievement, today)));
+        let contents = self
+            .unlocked
+            .iter()
+            .map(|(achievement, date)| format!("{} {}", achievement.id(), date.format("%Y-%m-%d")))
+            .collect::<Vec<String>>()
+            .join("\n");
+        storage::write(&self.path, contents)?;
+
+        Ok(new)
+    }
+}
+
+// the trophy case, locked ones included so there's something to aim for. returns how far it's
+// scrolled, see `help::scrolled`
+pub fn render(achievements: &Achievements, config: &Config, frame: &mut Frame, scroll: u16) -> u16 {
+    let mut lines = Achievement::ALL
+        .into_iter()
+        .map(|achievement| {
+            let name = Span::from(format!("{:<19}", achievement.name()));
+            let description = Span::from(achievement.description());
+            if achievements.is_unlocked(achievement) {
+                Line::from(vec![
+                    Span::from("★ ").fg(config.colors.correct),
+                    name.bold(),
+                    description,
+                ])
+            } else {
+                Line::from(vec![Span::from("☆ "), name, description]).dark_gray()
+            }
+        })
+        .collect::<Vec<Line>>();
+    lines.push(Line::default());
+    lines.push(
+        Line::from(format!(
+            "{} of {} unlocked",
+            achievements.unlocked.len(),
+            Achievement::ALL.len()
+        ))
+        .dark_gray(),
+    );
+
+    let (area, scroll) = help::scrolled(frame.area(), lines.len(), scroll);
+
+    let popup = Paragraph::new(lines).scroll((scroll, 0)).block(
+        Block::bordered()
+            .title(" achievements ")
+            .title_bottom(
+                Line::from(format!(" {} to close ", config.keys.close.primary())).centered(),
+            )
+            .border_style(Style::default().fg(Color::DarkGray))
+            .padding(Padding::horizontal(1)),
+    );
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
+    scroll
+}
@@ -0,0 +1,181 @@
+use ratatui::{
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{Block, Clear, Padding, Paragraph},
+    Frame,
+};
+use std::collections::{HashMap, HashSet};
+use wordle_tui::wordle_core::{score_guess, Game, Guess, LetterState};
+
+use crate::{config::Config, help};
+
+// how many of the words that still fit are listed after a loss
+const LEFT_OVER_SHOWN: usize = 5;
+
+// how much one guess narrowed things down
+#[derive(Debug, Clone, PartialEq)]
+pub struct GuessAnalysis {
+    pub word: String,
+    // words that fit everything revealed before and after this guess
+    pub before: usize,
+    pub after: usize,
+    // what `after` would be on average, over every word it could have been
+    pub expected: f64,
+    pub correct: bool,
+}
+
+impl GuessAnalysis {
+    // the share of remaining words it was expected to rule out
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    fn skill(&self) -> u8 {
+        if self.correct {
+            return 100;
+        }
+
+        (100.0 - self.expected * 100.0 / self.before as f64).clamp(0.0, 99.0) as u8
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn luck(&self) -> &'static str {
+        let after = self.after as f64;
+        if after * 2.0 <= self.expected {
+            "lucky"
+        } else if after >= self.expected * 2.0 {
+            "unlucky"
+        } else {
+            "-"
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Analysis {
+    pub guesses: Vec<GuessAnalysis>,
+    // what could still have been the answer after the last guess
+    pub left_over: Vec<String>,
+    pub solved: bool,
+}
+
+// replays the game against the whole word list. a word is still possible only if it would
+// have given exactly the colors every guess got, so this is stricter than the assist panel
+pub fn analyze(game: &Game, word_list: &HashSet<String>) -> Analysis {
+    let mut remaining = word_list.iter().map(String::as_str).collect::<Vec<&str>>();
+    remaining.sort_unstable();
+
+    let guesses = game
+        .guesses()
+        .iter()
+        .map(|guess| {
+            let before = remaining.len();
+            let expected = expected_remaining(&remaining, &guess.word);
+            remaining.retain(|word| score_guess(word, &guess.word) == guess.states);
+
+            GuessAnalysis {
+                word: guess.word.clone(),
+                before,
+                after: remaining.len(),
+                expected,
+                correct: guess.is_correct(),
+            }
+        })
+        .collect();
+
+    Analysis {
+        guesses,
+        left_over: remaining.into_iter().map(str::to_owned).collect(),
+        solved: game.guesses().last().is_some_and(Guess::is_correct),
+    }
+}
+
+// every possible answer splits into groups by the colors it'd give this guess
+fn groups(remaining: &[&str], guess: &str) -> HashMap<Vec<LetterState>, usize> {
+    let mut groups = HashMap::<Vec<LetterState>, usize>::new();
+    for word in remaining {
+        *groups.entry(score_guess(word, guess)).or_default() += 1;
+    }
+    groups
+}
+
+// the answer lands in a group that big with a chance that big too
+#[allow(clippy::cast_precision_loss)]
+fn expected_remaining(remaining: &[&str], guess: &str) -> f64 {
+    if remaining.is_empty() {
+        return 0.0;
+    }
+
+    let squares = groups(remaining, guess)
+        .values()
+        .map(|&size| size * size)
+        .sum::<usize>();
+    squares as f64 / remaining.len() as f64
+}
+
+// bits of information the colors are expected to give, the more evenly a guess splits what's
+// left the more it tells
+#[allow(clippy::cast_precision_loss)]
+pub fn entropy(remaining: &[&str], guess: &str) -> f64 {
+    let total = remaining.len() as f64;
+    groups(remaining, guess)
+        .values()
+        .map(|&size| {
+            let chance = size as f64 / total;
+            -chance * chance.log2()
+        })
+        .sum()
+}
+
+pub fn render(analysis: &Analysis, config: &Config, frame: &mut Frame) {
+    let mut lines = vec![Line::from(format!(
+        "{:<7}  {:>6}  {:>5}  {:>8}  {:>5}  luck",
+        "guess", "before", "after", "expected", "skill"
+    ))
+    .dark_gray()];
+    lines.extend(analysis.guesses.iter().map(|guess| {
+        Line::from(format!(
+            "{:<7}  {:>6}  {:>5}  {:>8.1}  {:>5}  {}",
+            guess.word,
+            guess.before,
+            guess.after,
+            guess.expected,
+            guess.skill(),
+            guess.luck()
+        ))
+    }));
+
+    if !analysis.solved {
+        let mut words =
+            analysis.left_over[..analysis.left_over.len().min(LEFT_OVER_SHOWN)].join(", ");
+        if analysis.left_over.len() > LEFT_OVER_SHOWN {
+            words.push_str(", ...");
+        }
+
+        lines.push(Line::default());
+        lines.push(Line::from(match analysis.left_over.len() {
+            1 => format!("1 word still fit: {words}"),
+            count => format!("{count} words still fit: {words}"),
+        }));
+    }
+
+    lines.push(Line::default());
+    lines.push(Line::from("skill: how much of what was left it should rule out").dark_gray());
+
+    #[allow(clippy::cast_possible_truncation)]
+    let area = help::centered(frame.area(), help::WIDTH, lines.len() as u16 + 2);
+
+    let popup = Paragraph::new(lines).block(
+        Block::bordered()
+            .title(" analysis ")
+            .title_bottom(
+                Line::from(format!(" {} to close ", config.keys.close.primary())).centered(),
+            )
+            .border_style(Style::default().fg(Color::DarkGray))
+            .padding(Padding::horizontal(1)),
+    );
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
+}
@@ -0,0 +1,108 @@
+use ratatui::{
+    style::Color,
+    text::{Line, Span},
+};
+use std::time::{Duration, Instant};
+
+const TILE_REVEAL_DELAY: Duration = Duration::from_millis(250);
+const SHAKE_DURATION: Duration = Duration::from_millis(400);
+const SHAKE_STEP: Duration = Duration::from_millis(50);
+
+#[derive(Debug)]
+pub struct Animations {
+    enabled: bool,
+    // how long a submitted row stays unrevealed before the first tile flips
+    reveal_delay: Duration,
+    // row being revealed, its tile count and when the reveal began
+    reveal: Option<(usize, usize, Instant)>,
+    shake: Option<Instant>,
+}
+
+impl Animations {
+    pub const fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            reveal_delay: Duration::ZERO,
+            reveal: None,
+            shake: None,
+        }
+    }
+
+    // still waited out with animations off, the tiles just all flip at once afterwards
+    pub const fn with_reveal_delay(mut self, reveal_delay: Duration) -> Self {
+        self.reveal_delay = reveal_delay;
+        self
+    }
+
+    // anything already playing finishes as it was
+    pub const fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    pub fn reveal(&mut self, row: usize, tiles: usize) {
+        if self.enabled || !self.reveal_delay.is_zero() {
+            self.reveal = Some((row, tiles, Instant::now()));
+        }
+    }
+
+    pub fn shake(&mut self) {
+        if self.enabled {
+            self.shake = Some(Instant::now());
+        }
+    }
+
+    // how many tiles of a row have flipped over, rows that aren't animating are fully revealed
+    pub fn revealed_tiles(&self, row: usize) -> usize {
+        match self.reveal {
+            Some((r, _, started)) if r == row => {
+                let Some(elapsed) = started.elapsed().checked_sub(self.reveal_delay) else {
+                    return 0;
+                };
+                if !self.enabled {
+                    return usize::MAX;
+                }
+
+                #[allow(clippy::cast_possible_truncation)]
+                let flipped = (elapsed.as_millis() / TILE_REVEAL_DELAY.as_millis()) as usize;
+                flipped + 1
+            }
+            _ => usize::MAX,
+        }
+    }
+
+    pub fn is_revealing(&self) -> bool {
+        self.reveal
+            .is_some_and(|(row, tiles, _)| self.revealed_tiles(row) < tiles)
+    }
+
+    // horizontal nudge for the input row, none once the shake has settled
+    pub fn shake_offset(&self) -> Option<i8> {
+        let elapsed = self.shake?.elapsed();
+        if elapsed >= SHAKE_DURATION {
+            return None;
+        }
+
+        let step = elapsed.as_millis() / SHAKE_STEP.as_millis();
+        Some(if step.is_multiple_of(2) { 1 } else { -1 })
+    }
+
+    // flashes a rejected guess red while nudging it side to side, padding one side shifts
+    // the centered line the other way
+    pub fn shake_line<'a>(&self, mut line: Line<'a>) -> Line<'a> {
+        let Some(offset) = self.shake_offset() else {
+            return line;
+        };
+
+        for span in &mut line.spans {
+            span.style = span.style.fg(Color::LightRed);
+        }
+        let padding = Span::from("  ");
+        if offset > 0 {
+            line.spans.insert(0, padding);
+        } else {
+            line.spans.push(padding);
+        }
+
+        line
+    }
+}
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::storage;
+
+// words people actually know, the full lists are mostly there so odd guesses are accepted
+const CURATED: &str = include_str!("answers.txt");
+// never picked as an answer whatever the pool, `*word*` rules out anything containing it
+const BLOCKED: &str = include_str!("blocked.txt");
+
+// how obscure randomly picked answers can get, guesses are checked against the whole list
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AnswerPool {
+    // common words without a repeated letter
+    Easy,
+    #[default]
+    Common,
+    // anything in the word list
+    Any,
+}
+
+fn is_blocked(word: &str) -> bool {
+    let word = word.to_lowercase();
+    BLOCKED.lines().any(|blocked| {
+        blocked
+            .strip_prefix('*')
+            .and_then(|blocked| blocked.strip_suffix('*'))
+            .map_or(word == blocked, |root| word.contains(root))
+    })
+}
+
+fn has_repeats(word: &str) -> bool {
+    let mut seen = HashSet::new();
+    !word.chars().all(|c| seen.insert(c))
+}
+
+// the words out of `word_list` that random answers come from. the list's own `answers` stand in
+// for the curated words when it has them. a pool with nothing in it, like the curated words
+// against a list in another language, falls back to the next one up
+pub fn pool(
+    word_list: &HashSet<String>,
+    answers: &HashSet<String>,
+    pool: AnswerPool,
+) -> HashSet<String> {
+    let allowed = word_list
+        .iter()
+        .filter(|word| !is_blocked(word))
+        .cloned()
+        .collect::<HashSet<String>>();
+    if pool == AnswerPool::Any {
+        return allowed;
+    }
+
+    let common = if answers.is_empty() {
+        CURATED
+            .lines()
+            .map(str::to_uppercase)
+            .filter(|word| allowed.contains(word))
+            .collect::<HashSet<String>>()
+    } else {
+        answers
+            .iter()
+            .filter(|word| allowed.contains(*word))
+            .cloned()
+            .collect()
+    };
+    let easy = common
+        .iter()
+        .filter(|word| !has_repeats(word))
+        .cloned()
+        .collect::<HashSet<String>>();
+
+    match pool {
+        AnswerPool::Easy if !easy.is_empty() => easy,
+        _ if !common.is_empty() => common,
+        _ => allowed,
+    }
+}
+
+// the same word for the same seed on anyone's machine, as long as the word list and answer
+// pool match. every board of a multi-board game gets its own
+pub fn seeded(pool: &HashSet<String>, seed: u64, board: usize) -> Option<String> {
+    let mut words = pool.iter().collect::<Vec<&String>>();
+    if words.is_empty() {
+        return None;
+    }
+    words.sort_unstable();
+
+    let hash = storage::hash(seed.to_le_bytes().into_iter().chain(board.to_le_bytes()));
+    #[allow(clippy::cast_possible_truncation)]
+    Some(words[(hash % words.len() as u64) as usize].clone())
+}
@@ -0,0 +1,200 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, Padding, Paragraph},
+    Frame,
+};
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
+use wordle_tui::wordle_core::LetterState;
+
+use crate::board::HashedLetterIndex;
+
+// room for a column per letter of the longest words
+pub const WIDTH: u16 = 23;
+
+// what the side panel shows
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Panel {
+    // every word that still fits
+    Words,
+    // how often each letter shows up in each spot among those words, without naming any
+    Letters,
+}
+
+// whether a panel has been looked at, any game where one was is marked as assisted
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Assist {
+    Unused,
+    Visible(Panel),
+    Hidden,
+}
+
+impl Assist {
+    // a resumed game remembers it was assisted, but not which panel was open
+    pub const fn restored(assisted: bool) -> Self {
+        if assisted {
+            Self::Hidden
+        } else {
+            Self::Unused
+        }
+    }
+
+    // switches straight to the other panel if one is already showing
+    pub fn toggle(self, panel: Panel) -> Self {
+        if self == Self::Visible(panel) {
+            Self::Hidden
+        } else {
+            Self::Visible(panel)
+        }
+    }
+
+    pub const fn used(self) -> bool {
+        !matches!(self, Self::Unused)
+    }
+}
+
+// every word that fits what's been revealed so far, the ones covering the most common letters
+// among them first
+pub fn candidates<'a>(
+    word_list: &'a HashSet<String>,
+    known_positions: &HashMap<HashedLetterIndex, LetterState>,
+    bad_characters: &HashSet<char>,
+) -> Vec<&'a str> {
+    let mut candidates = word_list
+        .iter()
+        .map(String::as_str)
+        .filter(|word| !word.chars().any(|c| bad_characters.contains(&c)))
+        .filter(|word| {
+            known_positions
+                .iter()
+                .all(|(HashedLetterIndex(letter, index), &state)| {
+                    let at_index = word.chars().nth(usize::from(*index)) == Some(*letter);
+                    match state {
+                        LetterState::Correct => at_index,
+                        LetterState::Present => !at_index && word.contains(*letter),
+                        LetterState::Absent => !at_index,
+                    }
+                })
+        })
+        .collect::<Vec<&str>>();
+
+    let mut frequencies = HashMap::<char, usize>::new();
+    for word in &candidates {
+        for letter in distinct_letters(word) {
+            *frequencies.entry(letter).or_default() += 1;
+        }
+    }
+
+    let score = |word: &str| -> usize {
+        distinct_letters(word)
+            .iter()
+            .map(|letter| frequencies.get(letter).copied().unwrap_or(0))
+            .sum()
+    };
+    candidates.sort_by_cached_key(|word| (Reverse(score(word)), *word));
+
+    candidates
+}
+
+fn distinct_letters(word: &str) -> HashSet<char> {
+    word.chars().collect()
+}
+
+pub fn render(
+    panel: Panel,
+    candidates: &[&str],
+    word_length: usize,
+    frame: &mut Frame,
+    area: Rect,
+) {
+    match panel {
+        Panel::Words => render_words(candidates, frame, area),
+        Panel::Letters => render_letters(candidates, word_length, frame, area),
+    }
+}
+
+fn render_words(candidates: &[&str], frame: &mut Frame, area: Rect) {
+    let shown = usize::from(area.height.saturating_sub(2));
+    let items = candidates
+        .iter()
+        .take(shown)
+        .map(|&word| ListItem::new(Line::from(word).centered()))
+        .collect::<Vec<ListItem>>();
+
+    let list = List::new(items).block(
+        Block::bordered()
+            .title(Line::from(format!(" {} left ", candidates.len())).centered())
+            .border_style(Style::default().fg(Color::DarkGray))
+            .padding(Padding::horizontal(1)),
+    );
+    frame.render_widget(list.fg(Color::White), area);
+}
+
+// the letters seen in each spot, most common first
+fn position_frequencies(candidates: &[&str], word_length: usize) -> Vec<Vec<(char, usize)>> {
+    let mut counts = vec![HashMap::<char, usize>::new(); word_length];
+    for word in candidates {
+        for (index, letter) in word.chars().take(word_length).enumerate() {
+            *counts[index].entry(letter).or_default() += 1;
+        }
+    }
+
+    counts
+        .into_iter()
+        .map(|counts| {
+            let mut counts = counts.into_iter().collect::<Vec<(char, usize)>>();
+            counts.sort_unstable_by_key(|&(letter, count)| (Reverse(count), letter));
+            counts
+        })
+        .collect()
+}
+
+// a column per spot in the word, hotter letters are more likely to go there
+fn render_letters(candidates: &[&str], word_length: usize, frame: &mut Frame, area: Rect) {
+    let frequencies = position_frequencies(candidates, word_length);
+    let shown = usize::from(area.height.saturating_sub(3));
+    let total = candidates.len().max(1);
+
+    let mut lines = vec![Line::from(
+        (1..=word_length)
+            .map(|position| Span::from(format!(" {position} ")))
+            .collect::<Vec<Span>>(),
+    )
+    .dark_gray()
+    .centered()];
+    lines.extend((0..shown).map(|rank| {
+        Line::from(
+            frequencies
+                .iter()
+                .map(|letters| {
+                    letters.get(rank).map_or_else(
+                        || Span::from("   "),
+                        |&(letter, count)| heat(letter, count * 100 / total),
+                    )
+                })
+                .collect::<Vec<Span>>(),
+        )
+        .centered()
+    }));
+
+    let panel = Paragraph::new(lines).block(
+        Block::bordered()
+            .title(Line::from(format!(" {} left ", candidates.len())).centered())
+            .border_style(Style::default().fg(Color::DarkGray)),
+    );
+    frame.render_widget(panel, area);
+}
+
+fn heat(letter: char, percentage: usize) -> Span<'static> {
+    let span = Span::from(format!(" {} ", letter.to_ascii_uppercase()));
+    match percentage {
+        50.. => span.fg(Color::LightRed).bold(),
+        20..50 => span.fg(Color::LightYellow),
+        5..20 => span.fg(Color::White),
+        _ => span.fg(Color::DarkGray),
+    }
+}
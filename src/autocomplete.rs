@@ -0,0 +1,70 @@
+use ratatui::{
+    style::{Color, Stylize},
+    text::Span,
+};
+
+// which of the words starting with what's been typed is being suggested. moving through them
+// only lasts while the same letters are typed, anything else starts back at the first one
+#[derive(Debug, Default)]
+pub struct Autocomplete {
+    prefix: String,
+    selected: usize,
+}
+
+impl Autocomplete {
+    fn selected(&self, input: &str, count: usize) -> usize {
+        if self.prefix == input && count > 0 {
+            self.selected % count
+        } else {
+            0
+        }
+    }
+
+    pub fn next(&mut self, input: &str, count: usize) {
+        self.selected = (self.selected(input, count) + 1) % count.max(1);
+        input.clone_into(&mut self.prefix);
+    }
+
+    pub fn previous(&mut self, input: &str, count: usize) {
+        self.selected = (self.selected(input, count) + count.max(1) - 1) % count.max(1);
+        input.clone_into(&mut self.prefix);
+    }
+
+    pub fn suggestion<'a>(&self, input: &str, words: &[&'a str]) -> Option<&'a str> {
+        words.get(self.selected(input, words.len())).copied()
+    }
+
+    // the rest of the suggested word, dimmed after what's typed, and where it is among the
+    // others when there's more than one
+    pub fn ghost(&self, input: &str, words: &[&str]) -> Vec<Span<'static>> {
+        let Some(word) = self.suggestion(input, words) else {
+            return Vec::new();
+        };
+
+        let mut spans = vec![Span::from(word[input.len()..].to_owned()).fg(Color::DarkGray)];
+        if words.len() > 1 {
+            spans.push(
+                Span::from(format!(
+                    "  {}/{}",
+                    self.selected(input, words.len()) + 1,
+                    words.len()
+                ))
+                .dark_gray()
+                .dim(),
+            );
+        }
+        spans
+    }
+}
+
+// the words that finish what's been typed, in the order they came
+pub fn matching<'a>(words: impl IntoIterator<Item = &'a str>, input: &str) -> Vec<&'a str> {
+    if input.is_empty() {
+        return Vec::new();
+    }
+
+    words
+        .into_iter()
+        .filter(|word| word.len() > input.len() && word.starts_with(input))
+        .collect()
+}
@@ -0,0 +1,249 @@
+use anyhow::{bail, Result};
+use fastrand::Rng;
+use std::{collections::HashSet, num::NonZero, str::FromStr, thread, time::Instant};
+use wordle_tui::wordle_core::{Game, GameStatus, MAX_GUESSES};
+
+use crate::{analysis, board::Board, storage};
+
+const HISTOGRAM_WIDTH: usize = 40;
+
+// entropy is only worked out for this many of the candidates, most common letters first.
+// going through all of them takes too long while there are still thousands left
+const ENTROPY_SHORTLIST: usize = 200;
+
+// how the next guess is picked out of every word that still fits
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum Strategy {
+    // the word covering the most common letters among what's left, same as the assist panel
+    #[default]
+    Frequency,
+    // any word that fits, as a baseline
+    Random,
+    // the word whose colors are expected to tell the most about what's left
+    Entropy,
+}
+
+impl FromStr for Strategy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "frequency" => Ok(Self::Frequency),
+            "random" => Ok(Self::Random),
+            "entropy" => Ok(Self::Entropy),
+            other => bail!("unknown strategy {other}, expected frequency, random or entropy"),
+        }
+    }
+}
+
+impl Strategy {
+    pub const ALL: [Self; 3] = [Self::Frequency, Self::Random, Self::Entropy];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Frequency => "frequency",
+            Self::Random => "random",
+            Self::Entropy => "entropy",
+        }
+    }
+
+    pub fn solver(self) -> &'static dyn Solver {
+        match self {
+            Self::Frequency => &Frequency,
+            Self::Random => &Random,
+            Self::Entropy => &Entropy,
+        }
+    }
+}
+
+// picks guesses for a strategy, out of the words that still fit in the order the assist panel
+// has them
+pub trait Solver: Sync {
+    // the words to guess, best first. later ones are only tried when an earlier one can't be
+    // played
+    fn order<'a>(&self, candidates: Vec<&'a str>, rng: &mut Rng) -> Vec<&'a str>;
+
+    // whether the same words always come out in the same order, so the opening only has to be
+    // worked out once for every game
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+}
+
+struct Frequency;
+
+impl Solver for Frequency {
+    fn order<'a>(&self, candidates: Vec<&'a str>, _rng: &mut Rng) -> Vec<&'a str> {
+        candidates
+    }
+}
+
+struct Random;
+
+impl Solver for Random {
+    fn order<'a>(&self, mut candidates: Vec<&'a str>, rng: &mut Rng) -> Vec<&'a str> {
+        rng.shuffle(&mut candidates);
+        candidates
+    }
+
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+}
+
+struct Entropy;
+
+impl Solver for Entropy {
+    fn order<'a>(&self, mut candidates: Vec<&'a str>, _rng: &mut Rng) -> Vec<&'a str> {
+        let shortlist = candidates.len().min(ENTROPY_SHORTLIST);
+        let mut scored = candidates[..shortlist]
+            .iter()
+            .map(|&word| (word, analysis::entropy(&candidates, word)))
+            .collect::<Vec<(&str, f64)>>();
+        // stable, so words that tell as much stay in frequency order
+        scored.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+        candidates.splice(..shortlist, scored.into_iter().map(|(word, _)| word));
+        candidates
+    }
+}
+
+// plays every word in the list with `strategy` and prints how it went
+pub fn run(word_list: &HashSet<String>, strategy: Strategy, hard_mode: bool) -> Result<()> {
+    let mut answers = word_list.iter().map(String::as_str).collect::<Vec<&str>>();
+    answers.sort_unstable();
+    if answers.is_empty() {
+        bail!("word list is empty");
+    }
+
+    let started = Instant::now();
+    let results = play_all(&answers, word_list, strategy.solver(), hard_mode);
+
+    println!("{}", report(&results, strategy, hard_mode));
+    let threads = threads();
+    println!(
+        "took {:.1}s on {threads} thread{}",
+        started.elapsed().as_secs_f64(),
+        if threads == 1 { "" } else { "s" }
+    );
+
+    Ok(())
+}
+
+pub fn threads() -> usize {
+    thread::available_parallelism().map_or(1, NonZero::get)
+}
+
+// how many guesses each of `solutions` took, none where it ran out. split over every thread
+pub fn play_all(
+    solutions: &[&str],
+    word_list: &HashSet<String>,
+    solver: &dyn Solver,
+    hard_mode: bool,
+) -> Vec<Option<usize>> {
+    // nothing is known before the first guess, so it's the same for every word
+    let opening = Board::new(Game::new("", hard_mode)).candidates(word_list);
+    let opening = if solver.is_deterministic() {
+        solver.order(opening, &mut Rng::with_seed(0))
+    } else {
+        opening
+    };
+    let opening = &opening;
+
+    thread::scope(|scope| {
+        solutions
+            .chunks(solutions.len().div_ceil(threads()).max(1))
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|solution| solve(solution, word_list, opening, solver, hard_mode))
+                        .collect::<Vec<Option<usize>>>()
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("bench thread panicked"))
+            .collect()
+    })
+}
+
+// how many guesses it took, none if it ran out
+fn solve(
+    solution: &str,
+    word_list: &HashSet<String>,
+    opening: &[&str],
+    solver: &dyn Solver,
+    hard_mode: bool,
+) -> Option<usize> {
+    // seeded by the solution so every run plays the same games
+    let mut rng = Rng::with_seed(storage::hash(solution.bytes()));
+    let mut board = Board::new(Game::new(solution, hard_mode));
+    let mut guessed = HashSet::new();
+
+    while board.game.status() == GameStatus::InProgress {
+        let candidates = if board.game.guesses().is_empty() {
+            if solver.is_deterministic() {
+                opening.to_vec()
+            } else {
+                solver.order(opening.to_vec(), &mut rng)
+            }
+        } else {
+            solver.order(board.candidates(word_list), &mut rng)
+        };
+
+        // repeated letters can leave an earlier guess looking like it still fits
+        let guess = candidates
+            .into_iter()
+            .filter(|word| guessed.insert(*word))
+            .find(|word| board.submit(word, word_list).is_ok());
+        if guess.is_none() {
+            break;
+        }
+    }
+
+    (board.game.status() == GameStatus::Won).then(|| board.game.guesses().len())
+}
+
+// how many took each number of guesses, the last being the ones it ran out on
+pub fn distribution(results: &[Option<usize>]) -> [usize; MAX_GUESSES + 1] {
+    let mut distribution = [0_usize; MAX_GUESSES + 1];
+    for result in results {
+        distribution[result.map_or(MAX_GUESSES, |guesses| guesses - 1)] += 1;
+    }
+    distribution
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn report(results: &[Option<usize>], strategy: Strategy, hard_mode: bool) -> String {
+    let distribution = distribution(results);
+
+    let solved = results.iter().flatten().collect::<Vec<&usize>>();
+    let average = solved.iter().copied().sum::<usize>() as f64 / solved.len().max(1) as f64;
+    let most = distribution.iter().copied().max().unwrap_or(1).max(1);
+
+    let mut lines = vec![
+        format!(
+            "{} strategy{} over {} words",
+            strategy.name(),
+            if hard_mode { " in hard mode" } else { "" },
+            results.len()
+        ),
+        format!(
+            "solved {} ({:.1}%), {average:.2} guesses on average",
+            solved.len(),
+            solved.len() as f64 / results.len() as f64 * 100.0
+        ),
+    ];
+    for (index, &count) in distribution.iter().enumerate() {
+        let label = if index == MAX_GUESSES {
+            String::from("X")
+        } else {
+            (index + 1).to_string()
+        };
+        let bar = "█".repeat(count * HISTOGRAM_WIDTH / most);
+        lines.push(format!("{label} {bar:<HISTOGRAM_WIDTH$} {count}"));
+    }
+
+    lines.join("\n")
+}
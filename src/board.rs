@@ -0,0 +1,445 @@
+use fastrand::Rng;
+use ratatui::{
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, Paragraph},
+    Frame,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    hash::{Hash, Hasher},
+};
+use wordle_tui::wordle_core::{Game, Guess, GuessError, Hint, LetterState};
+
+use crate::{assist, config::Colors};
+
+// a bordered tile around each letter, wide enough for a [A] when symbols are on
+pub const TILE_WIDTH: u16 = 5;
+pub const TILE_HEIGHT: u16 = 3;
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct HashedLetterIndex(pub char, pub u8);
+macro_rules! impl_into_hli {
+    ($prim:ty) => {
+        impl From<(char, $prim)> for HashedLetterIndex {
+            fn from((letter, pos): (char, $prim)) -> Self {
+                #[allow(clippy::cast_possible_truncation)]
+                Self(letter, pos as u8)
+            }
+        }
+    };
+}
+impl_into_hli!(u8);
+impl_into_hli!(usize);
+
+impl Hash for HashedLetterIndex {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let letter_value = u16::from((self.0 as u8).wrapping_sub(b'A'));
+        state.write_u16((letter_value << 8) | u16::from(self.1));
+    }
+}
+
+// returns whether there was room for it
+pub fn type_letter(input: &mut String, c: char, word_length: usize) -> bool {
+    if input.chars().count() >= word_length || !c.is_alphabetic() {
+        return false;
+    }
+
+    input.extend(c.to_uppercase());
+    true
+}
+
+// a pasted word replaces whatever was typed, keeping only its letters and as many as fit.
+// returns whether there were any
+pub fn paste(input: &mut String, text: &str, word_length: usize) -> bool {
+    let letters = text
+        .chars()
+        .filter(|c| c.is_alphabetic())
+        .flat_map(char::to_uppercase)
+        .take(word_length)
+        .collect::<String>();
+    if letters.is_empty() {
+        return false;
+    }
+
+    *input = letters;
+    true
+}
+
+// "C absent, R wrong spot, A correct, ..." for anyone who can't make out the colors
+pub fn describe(guess: &Guess) -> String {
+    if guess.is_forfeited() {
+        return String::from("out of time, nothing revealed");
+    }
+
+    guess
+        .letters()
+        .map(|(letter, state)| {
+            let state = match state {
+                LetterState::Correct => "correct",
+                LetterState::Present => "wrong spot",
+                LetterState::Absent => "absent",
+            };
+            format!("{letter} {state}")
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+// "²" for a letter the answer has twice, small enough to sit in a corner
+pub fn superscript(count: usize) -> char {
+    "⁰¹²³⁴⁵⁶⁷⁸⁹".chars().nth(count).unwrap_or('⁺')
+}
+
+// what the guesses so far have given away
+#[derive(Debug, Default)]
+struct Knowledge {
+    known_positions: HashMap<HashedLetterIndex, LetterState>,
+    bad_characters: HashSet<char>,
+    // the most copies of each letter one guess has found, the answer has at least that many
+    counts: HashMap<char, usize>,
+}
+
+// one solution's side of the game, the guesses so far and what those have given away
+#[derive(Debug)]
+pub struct Board {
+    pub game: Game,
+    // given in the order they were asked for
+    pub hints: Vec<Hint>,
+    // letters given away before the first guess, with `fill_in`
+    pub revealed: Vec<(usize, char)>,
+}
+
+impl Board {
+    pub const fn new(game: Game) -> Self {
+        Self {
+            game,
+            hints: Vec::new(),
+            revealed: Vec::new(),
+        }
+    }
+
+    // gives away `letters` of the answer, always leaving at least one to find
+    pub fn reveal(&mut self, letters: usize, rng: &mut Rng) {
+        let mut positions = (0..self.game.word_length()).collect::<Vec<usize>>();
+        rng.shuffle(&mut positions);
+        positions.truncate(letters.min(self.game.word_length().saturating_sub(1)));
+        positions.sort_unstable();
+
+        self.revealed = positions
+            .into_iter()
+            .filter_map(|index| Some((index, self.game.solution().chars().nth(index)?)))
+            .collect();
+    }
+
+    // what's been given away without a guess, for working out the next hint
+    pub fn given(&self) -> Vec<Hint> {
+        self.revealed
+            .iter()
+            .map(|&(index, letter)| Hint::Letter { index, letter })
+            .chain(self.hints.iter().cloned())
+            .collect()
+    }
+
+    pub fn submit(
+        &mut self,
+        guess: &str,
+        word_list: &HashSet<String>,
+    ) -> Result<Guess, GuessError> {
+        self.game.submit(guess, word_list).cloned()
+    }
+
+    // absurdle's host picks its answer out of `answers`
+    pub fn evade(
+        &mut self,
+        guess: &str,
+        word_list: &HashSet<String>,
+        answers: &HashSet<String>,
+    ) -> Result<Guess, GuessError> {
+        self.game.evade(guess, word_list, answers).cloned()
+    }
+
+    // worked out from the guesses every time rather than kept up to date alongside them, so
+    // it can't drift out of sync with restored or replayed games
+    fn knowledge(&self) -> Knowledge {
+        let mut knowledge = Knowledge::default();
+        for guess in self.game.guesses() {
+            for (letter, _) in guess.letters() {
+                let colored = guess
+                    .letters()
+                    .filter(|&(c, state)| c == letter && state != LetterState::Absent)
+                    .count();
+                let count = knowledge.counts.entry(letter).or_default();
+                *count = (*count).max(colored);
+            }
+            for (index, (letter, state)) in guess.letters().enumerate() {
+                // extra copies of a letter are gray even when it's in the word, it's only
+                // missing if no copy in the guess got a color
+                if state == LetterState::Absent
+                    && !guess
+                        .letters()
+                        .any(|(c, state)| c == letter && state != LetterState::Absent)
+                {
+                    knowledge.bad_characters.insert(letter);
+                }
+
+                let known = knowledge
+                    .known_positions
+                    .entry((letter, index).into())
+                    .or_insert(state);
+                *known = (*known).max(state);
+            }
+        }
+        for hint in &self.hints {
+            match hint {
+                Hint::Letter { index, letter } => {
+                    knowledge
+                        .known_positions
+                        .insert((*letter, *index).into(), LetterState::Correct);
+                    let count = knowledge.counts.entry(*letter).or_default();
+                    *count = (*count).max(1);
+                }
+                Hint::Absent(letters) => knowledge.bad_characters.extend(letters),
+            }
+        }
+        for &(index, letter) in &self.revealed {
+            knowledge
+                .known_positions
+                .insert((letter, index).into(), LetterState::Correct);
+            let count = knowledge.counts.entry(letter).or_default();
+            *count = (*count).max(1);
+        }
+
+        knowledge
+    }
+
+    pub fn candidates<'a>(&self, word_list: &'a HashSet<String>) -> Vec<&'a str> {
+        let knowledge = self.knowledge();
+        assist::candidates(
+            word_list,
+            &knowledge.known_positions,
+            &knowledge.bad_characters,
+        )
+    }
+
+    // the best thing known about a letter anywhere in the word
+    pub fn letter_state(&self, letter: char) -> Option<LetterState> {
+        let knowledge = self.knowledge();
+        if knowledge.bad_characters.contains(&letter) {
+            return Some(LetterState::Absent);
+        }
+
+        knowledge
+            .known_positions
+            .iter()
+            .filter(|(HashedLetterIndex(c, _), _)| *c == letter)
+            .map(|(_, &state)| state)
+            .max()
+    }
+
+    // with `repeats`, a letter that's been found gives away how many copies the answer has,
+    // otherwise it's only what the guesses show
+    pub fn letter_count(&self, letter: char, repeats: bool) -> usize {
+        let count = self
+            .knowledge()
+            .counts
+            .get(&letter)
+            .copied()
+            .unwrap_or_default();
+        if repeats && count > 0 {
+            return self
+                .game
+                .solution()
+                .chars()
+                .filter(|&c| c == letter)
+                .count();
+        }
+
+        count
+    }
+
+    pub fn input_line<'a>(&self, input: &'a str, colors: &Colors) -> Line<'a> {
+        let knowledge = self.knowledge();
+        let span_chars = input
+            .chars()
+            .enumerate()
+            .map(|(input_index, input_char)| {
+                if knowledge.bad_characters.contains(&input_char) {
+                    return (input_char, Some(LetterState::Absent));
+                }
+
+                // a gray extra copy only rules out that spot, the letter is still in the word
+                (
+                    input_char,
+                    knowledge
+                        .known_positions
+                        .get(&(input_char, input_index).into())
+                        .copied()
+                        .filter(|&state| state != LetterState::Absent),
+                )
+            })
+            .map(|(input_char, input_position)| colors.letter(input_char, input_position))
+            .collect::<Vec<Span>>();
+
+        Line::from(span_chars)
+    }
+
+    // hidden boards only show how many guesses have been made, for when a rival is watching
+    pub fn guesses_list(
+        &self,
+        colors: &Colors,
+        revealed_tiles: impl Fn(usize) -> usize,
+        hidden: bool,
+    ) -> List<'_> {
+        let guesses: Vec<ListItem> = self
+            .game
+            .guesses()
+            .iter()
+            .enumerate()
+            .map(|(row, guess)| {
+                if hidden {
+                    return ListItem::new(
+                        Line::from("•".repeat(self.game.word_length())).centered(),
+                    );
+                }
+
+                let revealed_tiles = revealed_tiles(row);
+                let colored_spans = guess
+                    .letters()
+                    .enumerate()
+                    // tiles that haven't flipped yet don't give anything away
+                    .map(|(i, (c, state))| {
+                        colors.tile_letter(c, (i < revealed_tiles).then_some(state))
+                    })
+                    .collect::<Vec<Span>>();
+
+                ListItem::new(Line::from(colored_spans).centered())
+            })
+            .collect();
+
+        List::new(guesses)
+            .style(Style::default().fg(Color::White))
+            .highlight_style(Style::default().fg(Color::Yellow))
+            .highlight_symbol(">")
+    }
+
+    // a tile for every letter and an empty row for every guess still to come, centered in
+    // `area`. falls back to one line per guess when there isn't room for that. with `repeats`
+    // found letters the answer has more than one of get their count in the tile's corner.
+    // returns where the tiles went, if they did
+    pub fn render_tiles(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        colors: &Colors,
+        repeats: bool,
+        revealed_tiles: impl Fn(usize) -> usize,
+    ) -> Option<Rect> {
+        let (rows, columns) = (self.game.max_guesses(), self.game.word_length());
+        #[allow(clippy::cast_possible_truncation)]
+        let (width, height) = (columns as u16 * TILE_WIDTH, rows as u16 * TILE_HEIGHT);
+        if area.width < width || area.height < height {
+            frame.render_widget(self.guesses_list(colors, revealed_tiles, false), area);
+            return None;
+        }
+
+        let [board_area] = Layout::horizontal([Constraint::Length(width)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [board_area] = Layout::vertical([Constraint::Length(height)])
+            .flex(Flex::Center)
+            .areas(board_area);
+        let row_areas =
+            Layout::vertical(vec![Constraint::Length(TILE_HEIGHT); rows]).split(board_area);
+
+        for (row, row_area) in row_areas.iter().enumerate() {
+            let guess = self.game.guesses().get(row);
+            let revealed_tiles = revealed_tiles(row);
+            let tile_areas =
+                Layout::horizontal(vec![Constraint::Length(TILE_WIDTH); columns]).split(*row_area);
+
+            for (column, &tile_area) in tile_areas.iter().enumerate() {
+                if row == self.game.guesses().len() {
+                    if let Some(&(_, c)) = self.revealed.iter().find(|&&(i, _)| i == column) {
+                        render_revealed(frame, tile_area, c, colors);
+                        continue;
+                    }
+                }
+                let letter = guess.and_then(|guess| guess.letters().nth(column));
+                // tiles that haven't flipped yet don't give anything away
+                let state = letter
+                    .filter(|_| column < revealed_tiles)
+                    .map(|(_, state)| state);
+                let border = state.map_or(Color::DarkGray, |state| colors.position(state));
+
+                let mut tile = Block::bordered().border_style(Style::default().fg(border));
+                if state.is_none() {
+                    tile = tile.dim();
+                }
+                if let Some((c, _)) =
+                    letter.filter(|_| repeats && state.is_some_and(|s| s != LetterState::Absent))
+                {
+                    let count = self.letter_count(c, true);
+                    if count > 1 {
+                        tile = tile.title_top(
+                            Line::from(superscript(count).to_string())
+                                .right_aligned()
+                                .fg(border),
+                        );
+                    }
+                }
+                // only inside the border, so tiles of the same color don't run together
+                let fill = state.and_then(|state| colors.fill(state));
+                let content = letter.map_or_else(Line::default, |(c, _)| {
+                    let letter = fill.map_or_else(
+                        || colors.letter(c, state),
+                        |fill| Span::from(c.to_string()).style(fill),
+                    );
+                    Line::from(letter.bold()).centered()
+                });
+                let inner = tile.inner(tile_area);
+                frame.render_widget(tile, tile_area);
+                frame.render_widget(
+                    Paragraph::new(content).style(fill.unwrap_or_default()),
+                    inner,
+                );
+            }
+        }
+
+        Some(board_area)
+    }
+}
+
+// a letter given away by `fill_in`, waiting in the row up next as a dimmed green
+fn render_revealed(frame: &mut Frame, area: Rect, letter: char, colors: &Colors) {
+    let tile = Block::bordered()
+        .border_style(Style::default().fg(colors.position(LetterState::Correct)))
+        .dim();
+    let inner = tile.inner(area);
+    frame.render_widget(tile, area);
+    frame.render_widget(
+        Paragraph::new(
+            Line::from(colors.letter(letter, Some(LetterState::Correct)).bold()).centered(),
+        )
+        .dim(),
+        inner,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_gray_extra_copy_leaves_the_letter_in_the_word() {
+        let words = ["CRANE", "SPEED"].map(String::from).into();
+        let mut board = Board::new(Game::new("CRANE", false));
+        // crane has one e, so speed's first is yellow and its second gray
+        assert!(board.submit("SPEED", &words).is_ok());
+
+        assert!(!board.knowledge().bad_characters.contains(&'E'));
+        assert_eq!(board.letter_state('E'), Some(LetterState::Present));
+        assert_eq!(board.letter_state('D'), Some(LetterState::Absent));
+    }
+}
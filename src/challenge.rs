@@ -0,0 +1,43 @@
+use anyhow::{ensure, Context, Result};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+
+use crate::cli::WORD_LENGTHS;
+
+// mixed in before encoding so the word can't be read straight off the token, it's not
+// meant to stop anyone determined
+const KEY: &[u8] = b"wordle-tui";
+
+pub fn encode(word: &str) -> String {
+    obscure(&word.to_uppercase())
+}
+
+pub fn decode(token: &str) -> Result<String> {
+    let bytes = unobscure(token.trim()).context("not a valid challenge")?;
+    ensure!(
+        WORD_LENGTHS.contains(&bytes.len()) && bytes.chars().all(|c| c.is_ascii_uppercase()),
+        "not a valid challenge"
+    );
+
+    Ok(bytes)
+}
+
+// any text, so it isn't sitting there in plain sight
+pub fn obscure(text: &str) -> String {
+    URL_SAFE_NO_PAD.encode(scramble(text.as_bytes()))
+}
+
+pub fn unobscure(token: &str) -> Option<String> {
+    URL_SAFE_NO_PAD
+        .decode(token)
+        .ok()
+        .map(|bytes| scramble(&bytes))
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+}
+
+fn scramble(bytes: &[u8]) -> Vec<u8> {
+    bytes
+        .iter()
+        .zip(KEY.iter().cycle())
+        .map(|(byte, key)| byte ^ key)
+        .collect()
+}
@@ -0,0 +1,347 @@
+use anyhow::{bail, ensure, Context, Result};
+use std::{env, ops::RangeInclusive, path::PathBuf, str::FromStr};
+use wordle_tui::wordle_core::WORD_LENGTH;
+
+use crate::{
+    bench::Strategy, challenge, config::ShareFormat, duel::DuelKind, extra_words, profile,
+    user_data, word_list::WordListSource,
+};
+
+// past this the boards get too small to read
+const MAX_BOARDS: usize = 8;
+// the board and side panels are laid out with room for up to seven tiles
+pub const WORD_LENGTHS: RangeInclusive<usize> = 4..=7;
+
+#[derive(Debug, Default)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Args {
+    pub hard_mode: bool,
+    pub offline: bool,
+    pub practice: bool,
+    pub speedrun: bool,
+    pub wait: bool,
+    // --wait that also comes back to the countdown after each game, instead of exiting
+    pub daemon: bool,
+    pub history: bool,
+    pub refresh_word_list: bool,
+    pub no_tui: bool,
+    pub accessible: bool,
+    pub no_color: bool,
+    pub json: bool,
+    pub streamer: bool,
+    pub blitz: bool,
+    pub absurdle: bool,
+    pub utc: bool,
+    // everything in the log, not just the game's own
+    pub verbose: bool,
+    // set by the bench subcommand
+    pub bench: Option<Strategy>,
+    // set by the simulate subcommand, with the strategies to play against each other
+    pub simulate: Option<Vec<Strategy>>,
+    pub wordlist: Option<extra_words::Command>,
+    // set by the export-data and import-data subcommands
+    pub user_data: Option<user_data::Command>,
+    pub games: Option<usize>,
+    pub duel: Option<DuelKind>,
+    // the relay room to race everyone else in
+    pub royale: Option<String>,
+    pub boards: Option<usize>,
+    // how many of the answer's letters are given away from the start
+    pub fill_in: Option<usize>,
+    // a practice word everyone with the same seed gets
+    pub seed: Option<u64>,
+    pub length: Option<usize>,
+    pub challenge: Option<String>,
+    // print a challenge for this word instead of playing
+    pub create_challenge: Option<String>,
+    pub word_list: Option<WordListSource>,
+    pub export_json: Option<PathBuf>,
+    pub export_image: Option<PathBuf>,
+    pub log: Option<PathBuf>,
+    pub share_format: Option<ShareFormat>,
+    pub config: Option<PathBuf>,
+    pub profile: Option<String>,
+}
+
+// the number given after `flag`
+fn number<T: FromStr>(raw_args: &mut impl Iterator<Item = String>, flag: &str) -> Result<T> {
+    let number = raw_args
+        .next()
+        .with_context(|| format!("{flag} needs a number"))?;
+    number
+        .parse()
+        .ok()
+        .with_context(|| format!("{flag} needs a number, not {number}"))
+}
+
+fn path(raw_args: &mut impl Iterator<Item = String>, flag: &str) -> Result<PathBuf> {
+    raw_args
+        .next()
+        .map(PathBuf::from)
+        .with_context(|| format!("{flag} needs a path"))
+}
+
+impl Args {
+    pub fn parse() -> Result<Self> {
+        let mut args = Self::default();
+
+        let mut raw_args = env::args().skip(1).peekable();
+        if raw_args.next_if(|arg| arg == "bench").is_some() {
+            args.bench = Some(Strategy::default());
+        } else if raw_args.next_if(|arg| arg == "simulate").is_some() {
+            args.simulate = Some(Vec::new());
+        } else if raw_args.next_if(|arg| arg == "wordlist").is_some() {
+            args.wordlist = Some(extra_words::Command::parse(&mut raw_args)?);
+        } else if let Some(name) =
+            raw_args.next_if(|arg| arg == "export-data" || arg == "import-data")
+        {
+            args.user_data = Some(user_data::Command::parse(&name, &mut raw_args)?);
+        }
+
+        while let Some(arg) = raw_args.next() {
+            if args.set_flag(&arg) || args.set_number(&arg, &mut raw_args)? {
+                continue;
+            }
+
+            match arg.as_str() {
+                "--duel" => args.duel = Some(DuelKind::SameWord),
+                "--duel-secret" => args.duel = Some(DuelKind::SecretWords),
+                "--royale" => {
+                    let room = raw_args.next().context("--royale needs a room name")?;
+                    ensure!(
+                        !room.is_empty()
+                            && room
+                                .chars()
+                                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'),
+                        "room names can only have letters, numbers, - and _"
+                    );
+                    args.royale = Some(room);
+                }
+                "--wordlist" => {
+                    args.word_list = Some(
+                        raw_args
+                            .next()
+                            .context("--wordlist needs a path, url, nyt or bundled")?
+                            .into(),
+                    );
+                }
+                "--challenge" => {
+                    args.challenge = Some(challenge::decode(
+                        &raw_args.next().context("--challenge needs a challenge")?,
+                    )?);
+                }
+                "--create-challenge" => {
+                    args.create_challenge = Some(
+                        raw_args
+                            .next()
+                            .context("--create-challenge needs a word")?
+                            .to_uppercase(),
+                    );
+                }
+                "--share-format" => {
+                    args.share_format = Some(
+                        raw_args
+                            .next()
+                            .context("--share-format needs emoji, ascii or ansi")?
+                            .parse()?,
+                    );
+                }
+                "--export-json" => args.export_json = Some(path(&mut raw_args, "--export-json")?),
+                "--export-image" => {
+                    args.export_image = Some(path(&mut raw_args, "--export-image")?);
+                }
+                "--games" if args.simulate.is_some() => {
+                    args.games = Some(number(&mut raw_args, "--games")?);
+                }
+                strategy if !strategy.starts_with('-') => match &mut args.simulate {
+                    Some(strategies) => strategies.push(strategy.parse()?),
+                    None => bail!("unknown argument {strategy}"),
+                },
+                "--log" => args.log = Some(path(&mut raw_args, "--log")?),
+                "--strategy" if args.bench.is_some() => {
+                    args.bench = Some(
+                        raw_args
+                            .next()
+                            .context("--strategy needs frequency or random")?
+                            .parse()?,
+                    );
+                }
+                "--profile" => {
+                    let profile = raw_args.next().context("--profile needs a name")?;
+                    profile::validate(&profile)?;
+                    args.profile = Some(profile);
+                }
+                "--config" => args.config = Some(path(&mut raw_args, "--config")?),
+                other => bail!("unknown argument {other}"),
+            }
+        }
+
+        args.wait |= args.daemon;
+        args.validate()?;
+        Ok(args)
+    }
+
+    // the flags that take a number after them, returns whether `arg` was one
+    fn set_number(
+        &mut self,
+        arg: &str,
+        raw_args: &mut impl Iterator<Item = String>,
+    ) -> Result<bool> {
+        match arg {
+            "--boards" => {
+                let boards = number(raw_args, "--boards")?;
+                ensure!(
+                    (1..=MAX_BOARDS).contains(&boards),
+                    "--boards must be between 1 and {MAX_BOARDS}"
+                );
+                self.boards = Some(boards);
+            }
+            "--fill-in" => {
+                let letters = number(raw_args, "--fill-in")?;
+                ensure!(
+                    letters > 0,
+                    "--fill-in needs at least one letter to give away"
+                );
+                self.fill_in = Some(letters);
+            }
+            "--seed" => {
+                self.seed = Some(number(raw_args, "--seed")?);
+                self.practice = true;
+            }
+            "--length" => {
+                let length = number(raw_args, "--length")?;
+                ensure!(
+                    WORD_LENGTHS.contains(&length),
+                    "--length must be between {} and {}",
+                    WORD_LENGTHS.start(),
+                    WORD_LENGTHS.end()
+                );
+                self.length = Some(length);
+            }
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+
+    // the flags that take nothing after them, returns whether `arg` was one
+    fn set_flag(&mut self, arg: &str) -> bool {
+        let flag = match arg {
+            "--hard" => &mut self.hard_mode,
+            "--offline" => &mut self.offline,
+            "--practice" => &mut self.practice,
+            "--speedrun" => &mut self.speedrun,
+            "--wait" => &mut self.wait,
+            "--daemon" => &mut self.daemon,
+            "--history" => &mut self.history,
+            "--refresh-wordlist" => &mut self.refresh_word_list,
+            "--no-tui" => &mut self.no_tui,
+            "--accessible" => &mut self.accessible,
+            "--no-color" => &mut self.no_color,
+            "--json" => &mut self.json,
+            "--streamer" => &mut self.streamer,
+            "--blitz" => &mut self.blitz,
+            "--absurdle" => &mut self.absurdle,
+            "--utc" => &mut self.utc,
+            "--verbose" => &mut self.verbose,
+            _ => return false,
+        };
+        *flag = true;
+        true
+    }
+
+    // a challenge's word carries its own length, so it doesn't need --length as well
+    pub fn word_length(&self) -> usize {
+        self.length
+            .or_else(|| {
+                self.challenge
+                    .as_ref()
+                    .or(self.create_challenge.as_ref())
+                    .map(|word| word.chars().count())
+            })
+            .unwrap_or(WORD_LENGTH)
+    }
+
+    // flags that make no sense together
+    fn validate(&self) -> Result<()> {
+        ensure!(
+            self.duel.is_none() || self.boards.is_none(),
+            "--boards can't be combined with a duel"
+        );
+        ensure!(
+            !self.no_tui || (self.duel.is_none() && self.boards.is_none()),
+            "--no-tui only plays a single board"
+        );
+        ensure!(
+            self.challenge.is_none()
+                || !(self.practice || self.duel.is_some() || self.boards.is_some()),
+            "--challenge plays a single word of its own"
+        );
+        // only the daily puzzle has a next one to wait for
+        ensure!(
+            !self.wait
+                || !(self.practice
+                    || self.absurdle
+                    || self.no_tui
+                    || self.duel.is_some()
+                    || self.boards.is_some()
+                    || self.challenge.is_some()
+                    || self.fill_in.is_some()),
+            "--wait and --daemon only work with the daily puzzle"
+        );
+        ensure!(
+            self.challenge
+                .iter()
+                .chain(&self.create_challenge)
+                .all(|word| self
+                    .length
+                    .is_none_or(|length| word.chars().count() == length)),
+            "--length doesn't match the challenge word"
+        );
+        ensure!(
+            !self.blitz || !(self.no_tui || self.duel.is_some()),
+            "--blitz needs the terminal ui and doesn't work in duels"
+        );
+        ensure!(
+            !self.absurdle
+                || !(self.practice
+                    || self.no_tui
+                    || self.duel.is_some()
+                    || self.boards.is_some()
+                    || self.challenge.is_some()),
+            "--absurdle plays a single board of its own in the terminal ui"
+        );
+        ensure!(
+            self.fill_in.is_none() || !(self.absurdle || self.duel.is_some()),
+            "--fill-in doesn't work with --absurdle or duels"
+        );
+        ensure!(
+            self.seed.is_none() || self.duel.is_none(),
+            "--seed can't be combined with a duel"
+        );
+        ensure!(
+            self.royale.is_none()
+                || !(self.no_tui
+                    || self.duel.is_some()
+                    || self.boards.is_some()
+                    || self.challenge.is_some()
+                    || self.absurdle
+                    || self.wait
+                    || self.blitz
+                    || self.fill_in.is_some()
+                    || self.seed.is_some()),
+            "--royale races on a single board of its own in the terminal ui"
+        );
+        ensure!(!self.json || self.no_tui, "--json only works with --no-tui");
+        ensure!(
+            !self.verbose || self.log.is_some(),
+            "--verbose needs a --log to write to"
+        );
+        ensure!(
+            !self.history || !self.no_tui,
+            "--history needs the terminal ui"
+        );
+
+        Ok(())
+    }
+}
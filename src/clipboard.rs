@@ -0,0 +1,11 @@
+use anyhow::{Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::io::{self, Write};
+
+// OSC 52 asks the terminal itself to set the clipboard, which also works over ssh
+pub fn copy(text: &str) -> Result<()> {
+    let mut stdout = io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", STANDARD.encode(text))
+        .and_then(|()| stdout.flush())
+        .context("failed to write to terminal")
+}
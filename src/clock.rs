@@ -0,0 +1,99 @@
+use anyhow::{bail, Context, Result};
+use chrono::{FixedOffset, Local, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+    time::Duration,
+};
+
+// whose midnight the daily puzzle changes at. the nyt goes by the device's own clock, so
+// anyone traveling or sharing a puzzle across timezones can pin it down instead.
+// written as "local", "utc" or an offset like "+05:30" in the config file
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Timezone {
+    #[default]
+    Local,
+    Utc,
+    Offset(FixedOffset),
+}
+
+impl Timezone {
+    // the wall clock time over there
+    pub fn now(self) -> NaiveDateTime {
+        match self {
+            Self::Local => Local::now().naive_local(),
+            Self::Utc => Utc::now().naive_utc(),
+            Self::Offset(offset) => Utc::now().with_timezone(&offset).naive_local(),
+        }
+    }
+
+    pub fn today(self) -> NaiveDate {
+        self.now().date()
+    }
+
+    // time left until the next daily puzzle unlocks
+    pub fn until_midnight(self) -> Duration {
+        let now = self.now();
+        now.date()
+            .succ_opt()
+            .and_then(|tomorrow| tomorrow.and_hms_opt(0, 0, 0))
+            .and_then(|midnight| (midnight - now).to_std().ok())
+            .unwrap_or_default()
+    }
+}
+
+impl FromStr for Timezone {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "local" => return Ok(Self::Local),
+            "utc" | "z" => return Ok(Self::Utc),
+            _ => {}
+        }
+
+        let (sign, rest) = match s.split_at_checked(1) {
+            Some(("+", rest)) => (1, rest),
+            Some(("-", rest)) => (-1, rest),
+            _ => bail!("unknown timezone {s}, expected local, utc or an offset like +05:30"),
+        };
+        let (hours, minutes) = rest.split_once(':').unwrap_or((rest, "0"));
+        let hours = hours
+            .parse::<i32>()
+            .with_context(|| format!("timezone {s} has a bad hour"))?;
+        let minutes = minutes
+            .parse::<i32>()
+            .with_context(|| format!("timezone {s} has a bad minute"))?;
+
+        FixedOffset::east_opt(sign * (hours * 60 * 60 + minutes * 60))
+            .filter(|_| minutes < 60)
+            .map(Self::Offset)
+            .with_context(|| format!("timezone {s} is out of range"))
+    }
+}
+
+impl TryFrom<String> for Timezone {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> Result<Self> {
+        s.parse()
+    }
+}
+
+impl From<Timezone> for String {
+    fn from(timezone: Timezone) -> Self {
+        timezone.to_string()
+    }
+}
+
+impl Display for Timezone {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Local => write!(f, "local"),
+            Self::Utc => write!(f, "utc"),
+            Self::Offset(offset) => write!(f, "{offset}"),
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use anyhow::Result;
+use chrono::NaiveDate;
+use crossterm::event::KeyCode;
+use ratatui::{
+    backend::Backend,
+    layout::{Constraint, Flex, Layout},
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{Paragraph, Wrap},
+    Terminal,
+};
+
+use crate::{
+    config::Config,
+    event_loop::{Event, Events},
+    keymap::Action,
+    play_state::PlayState,
+    stats::Stats,
+};
+
+// the latest daily puzzle played here, going by the stats and the saved game. if that's after
+// today the clock has gone back, or was ahead when it was played
+pub fn latest_played(stats: &Stats, saved: Option<&PlayState>) -> Option<NaiveDate> {
+    stats
+        .records()
+        .iter()
+        .map(|record| record.date)
+        .chain(saved.and_then(|saved| saved.date))
+        .max()
+}
+
+pub fn message(today: NaiveDate, latest: NaiveDate) -> String {
+    format!(
+        "the clock says it's {today}, but {latest}'s puzzle has already been played here. if the \
+         clock's wrong fix it and start again, anything played now is an archive game that \
+         doesn't count"
+    )
+}
+
+// asks what to do about it rather than quietly letting an earlier puzzle count, or refusing
+// to play at all. returns whether to play today's puzzle as an archive game
+pub fn run(
+    terminal: &mut Terminal<impl Backend>,
+    events: &mut impl Events,
+    config: &Config,
+    today: NaiveDate,
+    latest: NaiveDate,
+) -> Result<bool> {
+    loop {
+        terminal.draw(|frame| {
+            let [_, message_area, hint_area, _] = Layout::vertical([
+                Constraint::Percentage(30),
+                Constraint::Length(5),
+                Constraint::Length(1),
+                Constraint::Min(0),
+            ])
+            .areas(frame.area());
+
+            let [message_area] = Layout::horizontal([Constraint::Max(60)])
+                .flex(Flex::Center)
+                .areas(message_area);
+            frame.render_widget(
+                Paragraph::new(vec![
+                    Line::from("has the clock gone back?").bold(),
+                    Line::from(message(today, latest)),
+                ])
+                .centered()
+                .wrap(Wrap { trim: true }),
+                message_area,
+            );
+            frame.render_widget(
+                Paragraph::new(format!(
+                    "a to play it as an archive game, {} to quit",
+                    config.keys.close.primary()
+                ))
+                .style(Style::default().fg(Color::DarkGray).dim())
+                .centered(),
+                hint_area,
+            );
+        })?;
+
+        let Event::Key(key_event) = events.next()? else {
+            continue;
+        };
+        if config
+            .keys
+            .action(key_event, &[Action::Quit, Action::Close])
+            .is_some()
+        {
+            return Ok(false);
+        }
+        if key_event.code == KeyCode::Char('a') {
+            return Ok(true);
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use ratatui::{
+    backend::{Backend, ClearType, WindowSize},
+    buffer::Cell,
+    layout::{Position, Size},
+    style::Color,
+};
+use serde::{Deserialize, Serialize};
+use std::{env, io};
+
+use crate::config::Colors;
+
+// the eight colors every terminal has, roughly as xterm shows them
+const BASIC: [(Color, (u8, u8, u8)); 8] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+];
+// the steps of the 256 color palette's 6×6×6 cube
+const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+// how many colors the terminal can show. anything it can't is drawn in the closest one it can
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ColorSupport {
+    // worked out from NO_COLOR, COLORTERM and TERM
+    #[default]
+    Auto,
+    // letters are told apart by symbols alone, same as --no-color
+    None,
+    // only the eight basic colors, the light ones are missing or hard to read on minimal
+    // terminals like the linux console
+    #[serde(rename = "16")]
+    Basic,
+    #[serde(rename = "256")]
+    Indexed,
+    TrueColor,
+}
+
+impl ColorSupport {
+    // what `Auto` comes out as here, anything else is kept as it was set
+    pub fn detect(self) -> Self {
+        if self != Self::Auto {
+            return self;
+        }
+
+        // https://no-color.org
+        if env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+            return Self::None;
+        }
+        let colorterm = env::var("COLORTERM").unwrap_or_default();
+        if colorterm == "truecolor" || colorterm == "24bit" || env::var_os("WT_SESSION").is_some() {
+            return Self::TrueColor;
+        }
+        match env::var("TERM").unwrap_or_default().as_str() {
+            "dumb" => Self::None,
+            term if term.contains("256color") => Self::Indexed,
+            // a terminal that doesn't say is assumed to be a basic one
+            _ => Self::Basic,
+        }
+    }
+
+    pub fn downgrade(self, color: Color) -> Color {
+        match (self, color) {
+            (Self::Auto | Self::TrueColor, color) | (_, color @ Color::Reset) => color,
+            (Self::None, _) => Color::Reset,
+            (Self::Indexed, Color::Rgb(r, g, b)) => indexed(r, g, b),
+            (Self::Indexed, color) => color,
+            (Self::Basic, color) => basic(color),
+        }
+    }
+
+    // with too few colors to tell the letter states apart, or from letters nothing is known
+    // about yet (white), symbols have to do it instead
+    pub fn needs_symbols(self, colors: &Colors) -> bool {
+        let states = [
+            colors.correct,
+            colors.wrong_placement,
+            colors.absent,
+            Color::White,
+        ]
+        .map(|color| self.downgrade(color));
+        (0..states.len()).any(|i| states[i + 1..].contains(&states[i]))
+    }
+}
+
+// the closest color in the 6×6×6 cube
+fn indexed(r: u8, g: u8, b: u8) -> Color {
+    let step = |value: u8| {
+        (0..6_u8)
+            .min_by_key(|&i| CUBE[usize::from(i)].abs_diff(value))
+            .unwrap_or(0)
+    };
+    Color::Indexed(16 + 36 * step(r) + 6 * step(g) + step(b))
+}
+
+// what a 256 color palette entry looks like, for working out which basic color is closest
+fn palette(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => BASIC[usize::from(index % 8)].1,
+        16..=231 => {
+            let i = index - 16;
+            (
+                CUBE[usize::from(i / 36)],
+                CUBE[usize::from(i / 6 % 6)],
+                CUBE[usize::from(i % 6)],
+            )
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        }
+    }
+}
+
+fn basic(color: Color) -> Color {
+    let (r, g, b) = match color {
+        Color::LightRed => return Color::Red,
+        Color::LightGreen => return Color::Green,
+        Color::LightYellow => return Color::Yellow,
+        Color::LightBlue => return Color::Blue,
+        Color::LightMagenta => return Color::Magenta,
+        Color::LightCyan => return Color::Cyan,
+        // bright black is the one most often missing, it usually just disappears
+        Color::DarkGray | Color::White => return Color::Gray,
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Indexed(index) => palette(index),
+        color => return color,
+    };
+
+    let distance = |(br, bg, bb): (u8, u8, u8)| {
+        u32::from(r.abs_diff(br)).pow(2)
+            + u32::from(g.abs_diff(bg)).pow(2)
+            + u32::from(b.abs_diff(bb)).pow(2)
+    };
+    BASIC
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb))
+        .map_or(Color::Reset, |(color, _)| *color)
+}
+
+// draws everything through `support`, so nothing has to pick its colors with it in mind
+#[derive(Debug)]
+pub struct ColorBackend<B> {
+    backend: B,
+    support: ColorSupport,
+}
+
+impl<B> ColorBackend<B> {
+    pub const fn new(backend: B, support: ColorSupport) -> Self {
+        Self { backend, support }
+    }
+}
+
+impl<B: Backend> Backend for ColorBackend<B> {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        if matches!(self.support, ColorSupport::Auto | ColorSupport::TrueColor) {
+            return self.backend.draw(content);
+        }
+
+        let cells = content
+            .map(|(x, y, cell)| {
+                let mut cell = cell.clone();
+                cell.fg = self.support.downgrade(cell.fg);
+                cell.bg = self.support.downgrade(cell.bg);
+                (x, y, cell)
+            })
+            .collect::<Vec<(u16, u16, Cell)>>();
+        self.backend
+            .draw(cells.iter().map(|(x, y, cell)| (*x, *y, cell)))
+    }
+
+    fn append_lines(&mut self, n: u16) -> io::Result<()> {
+        self.backend.append_lines(n)
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.backend.hide_cursor()
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.backend.show_cursor()
+    }
+
+    fn get_cursor_position(&mut self) -> io::Result<Position> {
+        self.backend.get_cursor_position()
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
+        self.backend.set_cursor_position(position)
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        self.backend.clear()
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+        self.backend.clear_region(clear_type)
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        self.backend.size()
+    }
+
+    fn window_size(&mut self) -> io::Result<WindowSize> {
+        self.backend.window_size()
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.backend.flush()
+    }
+}
@@ -0,0 +1,80 @@
+use anyhow::Result;
+use chrono::NaiveDate;
+use ratatui::{
+    backend::Backend,
+    layout::{Constraint, Layout},
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::Paragraph,
+    Terminal,
+};
+
+use crate::{
+    config::Config,
+    event_loop::{Event, Events},
+    keymap::Action,
+    timer,
+};
+
+pub fn message(config: &Config) -> String {
+    format!(
+        "next puzzle in {}",
+        timer::format(config.timezone.until_midnight())
+    )
+}
+
+// sits on today's result until the date rolls over, returns whether it did rather than the
+// player quitting
+pub fn run(
+    terminal: &mut Terminal<impl Backend>,
+    events: &mut impl Events,
+    config: &Config,
+    today: NaiveDate,
+    share: &str,
+) -> Result<bool> {
+    while config.timezone.today() == today {
+        terminal.draw(|frame| {
+            let [_, area] = Layout::vertical([Constraint::Percentage(30), Constraint::Min(1)])
+                .areas(frame.area());
+
+            let mut lines = vec![Line::from("you already played today").bold()];
+            lines.extend(share.lines().map(|line| Line::from(strip_ansi(line))));
+            lines.push(Line::default());
+            lines.push(Line::from(message(config)).dark_gray());
+            lines.push(
+                Line::from(format!("{} to quit", config.keys.close.primary()))
+                    .style(Style::default().fg(Color::DarkGray).dim()),
+            );
+
+            frame.render_widget(Paragraph::new(lines).centered(), area);
+        })?;
+
+        if let Event::Key(key_event) = events.next()? {
+            let quit = config
+                .keys
+                .action(key_event, &[Action::Quit, Action::Close])
+                .is_some();
+            if quit {
+                return Ok(false);
+            }
+        }
+    }
+
+    Ok(true)
+}
+
+// ansi share grids are colored with escape codes, which would garble the screen here
+fn strip_ansi(line: &str) -> String {
+    let mut stripped = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // skip up to and including the letter that ends the sequence
+            chars.by_ref().find(char::is_ascii_alphabetic);
+        } else {
+            stripped.push(c);
+        }
+    }
+
+    stripped
+}
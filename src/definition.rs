@@ -0,0 +1,111 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::mpsc::{self, Receiver, TryRecvError},
+    thread,
+};
+use ureq::Agent;
+
+use crate::network;
+
+const API: &str = "https://api.dictionaryapi.dev/api/v2/entries/en";
+
+// just the parts of dictionaryapi.dev's entries that get shown
+#[derive(Debug, Deserialize)]
+struct Entry {
+    meanings: Vec<Meaning>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Meaning {
+    part_of_speech: String,
+    definitions: Vec<Sense>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Sense {
+    definition: String,
+}
+
+// "verb: seal the seams of something", the first sense of the first meaning
+fn fetch(agent: &Agent, word: &str) -> Result<Option<String>> {
+    let response = match network::call(&agent.get(&format!("{API}/{}", word.to_lowercase()))) {
+        Ok(response) => response,
+        // the api's way of saying it doesn't know the word
+        Err(err) if err.code() == Some(404) => return Ok(None),
+        Err(err) => return Err(err).context("failed to reach the dictionary"),
+    };
+
+    let entries = response
+        .into_json::<Vec<Entry>>()
+        .context("the dictionary sent something unexpected")?;
+    Ok(entries
+        .into_iter()
+        .flat_map(|entry| entry.meanings)
+        .find_map(|meaning| {
+            let sense = meaning.definitions.into_iter().next()?;
+            Some(format!("{}: {}", meaning.part_of_speech, sense.definition))
+        }))
+}
+
+// a `word<tab>definition` file of your own, checked first and the only thing used offline
+fn look_up_locally(dictionary: &Path, word: &str) -> Option<String> {
+    fs::read_to_string(dictionary)
+        .ok()?
+        .lines()
+        .filter_map(|line| line.split_once('\t'))
+        .find(|(entry, _)| entry.trim().eq_ignore_ascii_case(word))
+        .map(|(_, definition)| definition.trim().to_owned())
+}
+
+// what the answer means, looked up in the background once the game is over
+#[derive(Debug, Default)]
+pub enum Definition {
+    #[default]
+    Off,
+    Looking(Receiver<Result<Option<String>>>),
+    Found(String),
+    // not known, or couldn't be looked up, with why
+    Missing(String),
+}
+
+impl Definition {
+    // without an agent only the local dictionary is tried
+    pub fn look_up(word: &str, agent: Option<&Agent>, dictionary: Option<&PathBuf>) -> Self {
+        if let Some(definition) = dictionary.and_then(|path| look_up_locally(path, word)) {
+            return Self::Found(definition);
+        }
+        let Some(agent) = agent.cloned() else {
+            return Self::Missing(String::from("no definition offline"));
+        };
+
+        let (sender, receiver) = mpsc::channel();
+        let word = word.to_owned();
+        thread::spawn(move || {
+            // the receiver is only gone if the game was already closed
+            let _ = sender.send(fetch(&agent, &word));
+        });
+
+        Self::Looking(receiver)
+    }
+
+    pub fn poll(&mut self) {
+        let Self::Looking(receiver) = self else {
+            return;
+        };
+
+        *self = match receiver.try_recv() {
+            Ok(Ok(Some(definition))) => Self::Found(definition),
+            Ok(Ok(None)) => Self::Missing(String::from("no definition found")),
+            // the whole error is too long for the results, and it's only ever the network
+            Ok(Err(_)) => Self::Missing(String::from("couldn't reach the dictionary")),
+            Err(TryRecvError::Empty) => return,
+            Err(TryRecvError::Disconnected) => {
+                Self::Missing(String::from("the lookup stopped unexpectedly"))
+            }
+        };
+    }
+}
@@ -0,0 +1,388 @@
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Paragraph},
+    Frame,
+};
+use std::{collections::HashSet, mem};
+use wordle_tui::wordle_core::{Game, GameStatus, MAX_GUESSES};
+
+use crate::{
+    animation::Animations,
+    answers,
+    board::{self, Board},
+    config::Config,
+    event_loop::{Crossterm, Event, Events},
+    keymap::Action,
+    loading::Puzzle,
+    random_word, tui,
+    tui::Terminal,
+};
+
+const PLAYERS: usize = 2;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DuelKind {
+    // both players race to solve the same random word
+    SameWord,
+    // each player picks the word the other has to solve
+    SecretWords,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum DuelView {
+    // `player` is typing the word the other player will have to guess
+    Setup { player: usize },
+    Playing,
+    Results,
+}
+
+// two players taking turns at the keyboard, one guess each at a time
+#[derive(Debug)]
+struct Duel<'a> {
+    kind: DuelKind,
+    config: &'a Config,
+    word_list: HashSet<String>,
+    // what same word rounds are picked from
+    answers: HashSet<String>,
+    // every word in the list has this many letters, secrets included
+    word_length: usize,
+
+    boards: [Board; PLAYERS],
+    // shared since only one player is at the keyboard at a time
+    input: String,
+    secrets: [Option<String>; PLAYERS],
+    active: usize,
+    // who goes first this round, alternates between rematches
+    first: usize,
+    scores: [u32; PLAYERS],
+
+    animations: Animations,
+    // whose board the current reveal animation is playing on
+    revealing: usize,
+
+    view: DuelView,
+    exit: bool,
+}
+
+pub fn run(
+    terminal: &mut Terminal,
+    config: &Config,
+    puzzle: Puzzle,
+    word_length: usize,
+    kind: DuelKind,
+) -> Result<()> {
+    // replaced as soon as the round's words are known
+    let placeholder = || Board::new(Game::new("", config.hard_mode));
+    let mut duel = Duel {
+        kind,
+        config,
+        answers: answers::pool(&puzzle.word_list, &puzzle.answers, config.answer_pool),
+        word_list: puzzle.word_list,
+        word_length,
+        boards: [placeholder(), placeholder()],
+        input: String::new(),
+        secrets: [None, None],
+        active: 0,
+        first: 0,
+        scores: [0; PLAYERS],
+        animations: Animations::new(config.animations)
+            .with_reveal_delay(config.streamer.reveal_delay()),
+        revealing: 0,
+        view: DuelView::Playing,
+        exit: false,
+    };
+    duel.new_round();
+
+    let mut events = Crossterm::new(config.tick_rate());
+    while !duel.exit {
+        terminal.draw(|frame| duel.draw(frame))?;
+
+        match events.next()? {
+            Event::Key(key_event) => duel.handle_key_event(key_event),
+            Event::Paste(text) if duel.view != DuelView::Results => {
+                let _ = board::paste(&mut duel.input, &text, duel.word_length);
+            }
+            _ => {}
+        }
+    }
+
+    tui::restore();
+    println!("{}", duel.score_line());
+
+    Ok(())
+}
+
+impl Duel<'_> {
+    fn new_round(&mut self) {
+        self.active = self.first;
+        self.secrets = [None, None];
+
+        match self.kind {
+            DuelKind::SameWord => {
+                let solution = random_word(&self.answers).unwrap_or_default();
+                self.start(&[solution.clone(), solution]);
+            }
+            DuelKind::SecretWords => {
+                self.view = DuelView::Setup { player: self.first };
+            }
+        }
+    }
+
+    fn start(&mut self, solutions: &[String; PLAYERS]) {
+        self.boards = solutions
+            .clone()
+            .map(|solution| Board::new(Game::new(&solution, self.config.hard_mode)));
+        self.view = DuelView::Playing;
+    }
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) {
+        let keys = &self.config.keys;
+        if keys.quit.matches(key_event) {
+            self.exit = true;
+            return;
+        }
+
+        if self.view == DuelView::Results {
+            // let the last row finish flipping before anything can be skipped
+            if self.animations.is_revealing() {
+                return;
+            }
+
+            match keys.action(key_event, &[Action::NewRound, Action::Close]) {
+                Some(Action::NewRound) => {
+                    self.first = other(self.first);
+                    self.new_round();
+                }
+                Some(Action::Close) => self.exit = true,
+                _ => {}
+            }
+            return;
+        }
+
+        match keys.action(
+            key_event,
+            &[Action::Submit, Action::Backspace, Action::ClearInput],
+        ) {
+            Some(Action::Submit) => match self.view {
+                DuelView::Setup { player } => self.submit_secret(player),
+                _ => self.submit_guess(),
+            },
+            Some(Action::Backspace) => {
+                let _ = self.input.pop();
+            }
+            Some(Action::ClearInput) => self.input.clear(),
+            _ => {
+                if let KeyCode::Char(c) = key_event.code {
+                    let _ = board::type_letter(&mut self.input, c, self.word_length);
+                }
+            }
+        }
+    }
+
+    fn submit_secret(&mut self, player: usize) {
+        if !self.word_list.contains(&self.input) {
+            self.animations.shake();
+            return;
+        }
+
+        // the word a player picks is the one their opponent has to find
+        self.secrets[other(player)] = Some(mem::take(&mut self.input));
+
+        if let [Some(first), Some(second)] = &self.secrets {
+            let solutions = [first.clone(), second.clone()];
+            self.start(&solutions);
+        } else {
+            self.view = DuelView::Setup {
+                player: other(player),
+            };
+        }
+    }
+
+    fn submit_guess(&mut self) {
+        let board = &mut self.boards[self.active];
+        if board.submit(&self.input, &self.word_list).is_err() {
+            self.animations.shake();
+            return;
+        }
+        self.input.clear();
+
+        self.animations
+            .reveal(board.game.guesses().len() - 1, self.word_length);
+        self.revealing = self.active;
+
+        // a player who's done just sits out the rest of the round
+        let opponent = other(self.active);
+        if self.boards[opponent].game.status() == GameStatus::InProgress {
+            self.active = opponent;
+        } else if self.boards[self.active].game.status() != GameStatus::InProgress {
+            if let Some(winner) = self.winner() {
+                self.scores[winner] += 1;
+            }
+            self.view = DuelView::Results;
+        }
+    }
+
+    // whoever solved it in fewer guesses, none for a tie
+    fn winner(&self) -> Option<usize> {
+        let guesses = self.boards.each_ref().map(|board| {
+            (board.game.status() == GameStatus::Won).then(|| board.game.guesses().len())
+        });
+
+        match guesses {
+            [Some(first), Some(second)] if first == second => None,
+            [Some(first), Some(second)] if first < second => Some(0),
+            [Some(_), None] => Some(0),
+            [_, Some(_)] => Some(1),
+            [None, None] => None,
+        }
+    }
+
+    fn score_line(&self) -> String {
+        format!("player 1 {} - {} player 2", self.scores[0], self.scores[1])
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let [title_area, boards_area, bottom_area] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(1),
+            Constraint::Length(if self.view == DuelView::Results { 5 } else { 1 }),
+        ])
+        .areas(frame.area());
+
+        let title = Paragraph::new(vec![
+            Line::from(if self.config.hard_mode {
+                "Wordle duel (hard)"
+            } else {
+                "Wordle duel"
+            }),
+            Line::from(self.score_line()).dark_gray(),
+        ])
+        .style(Style::default().fg(self.config.colors.title).dim())
+        .centered();
+        frame.render_widget(title, title_area);
+
+        if let DuelView::Setup { player } = self.view {
+            self.draw_setup(frame, boards_area, player);
+            return;
+        }
+
+        let board_areas = Layout::horizontal([Constraint::Ratio(1, 2); PLAYERS]).split(boards_area);
+        for (player, board) in self.boards.iter().enumerate() {
+            self.draw_board(frame, board_areas[player], player, board);
+        }
+
+        if self.view == DuelView::Results && !self.animations.is_revealing() {
+            self.draw_results(frame, bottom_area);
+        }
+    }
+
+    fn draw_board(&self, frame: &mut Frame, area: Rect, player: usize, board: &Board) {
+        let playing = self.view == DuelView::Playing;
+        let active = playing && player == self.active;
+
+        let block = Block::bordered()
+            .title(Line::from(format!(" player {} ", player + 1)).centered())
+            .border_style(Style::default().fg(if active {
+                self.config.colors.title
+            } else {
+                Color::DarkGray
+            }));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let [guesses_area, input_area] =
+            Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(inner);
+
+        // with a shared word, whoever's at the keyboard mustn't see how the other is doing
+        let hidden = playing && !active && self.kind == DuelKind::SameWord;
+        let revealed_tiles = |row| {
+            if player == self.revealing {
+                self.animations.revealed_tiles(row)
+            } else {
+                usize::MAX
+            }
+        };
+        frame.render_widget(
+            board.guesses_list(&self.config.colors, revealed_tiles, hidden),
+            guesses_area,
+        );
+
+        if active {
+            let input_line = self
+                .animations
+                .shake_line(board.input_line(&self.input, &self.config.colors));
+            frame.render_widget(Paragraph::new(input_line).centered(), input_area);
+        }
+    }
+
+    fn draw_setup(&self, frame: &mut Frame, area: Rect, player: usize) {
+        let [_, prompt_area] =
+            Layout::vertical([Constraint::Percentage(30), Constraint::Min(1)]).areas(area);
+
+        let masked = self
+            .animations
+            .shake_line(Line::from("•".repeat(self.input.chars().count())));
+        let prompt = Paragraph::new(vec![
+            Line::from(format!(
+                "player {}, pick a word for player {} to guess",
+                player + 1,
+                other(player) + 1
+            )),
+            Line::from(format!("player {}, look away!", other(player) + 1)).dark_gray(),
+            Line::default(),
+            masked,
+        ])
+        .centered();
+        frame.render_widget(prompt, prompt_area);
+    }
+
+    fn draw_results(&self, frame: &mut Frame, area: Rect) {
+        let mut lines = self
+            .boards
+            .iter()
+            .enumerate()
+            .map(|(player, board)| {
+                if board.game.status() == GameStatus::Won {
+                    Line::from(format!(
+                        "player {} solved it in {}/{MAX_GUESSES}",
+                        player + 1,
+                        board.game.guesses().len()
+                    ))
+                    .fg(self.config.colors.correct)
+                } else {
+                    Line::from(vec![
+                        Span::from(format!("player {} missed ", player + 1)),
+                        Span::from(board.game.solution()).bold(),
+                    ])
+                    .fg(Color::LightRed)
+                }
+            })
+            .collect::<Vec<Line>>();
+
+        lines.push(
+            Line::from(self.winner().map_or_else(
+                || String::from("it's a tie"),
+                |winner| format!("player {} wins the round!", winner + 1),
+            ))
+            .bold(),
+        );
+        lines.push(
+            Line::from(format!(
+                "{} for a rematch, {} to quit",
+                self.config.keys.new_round.primary(),
+                self.config.keys.close.primary()
+            ))
+            .style(Style::default().fg(Color::DarkGray).dim()),
+        );
+
+        frame.render_widget(Paragraph::new(lines).centered(), area);
+    }
+}
+
+const fn other(player: usize) -> usize {
+    (player + 1) % PLAYERS
+}
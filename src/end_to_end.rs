@@ -0,0 +1,448 @@
+// whole games played through `App::run`, with a script of key presses standing in for the
+// terminal and a made up api standing in for the network
+use anyhow::{bail, Result};
+use chrono::{Days, NaiveDate};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{backend::TestBackend, Terminal};
+use std::{
+    collections::HashSet,
+    env, fs,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+use ureq::Agent;
+use wordle_tui::wordle_core::{GameStatus, LetterState};
+use LetterState::{Absent as A, Correct as C, Present as P};
+
+use crate::{
+    answers,
+    cli::Args,
+    config::{Config, Paths},
+    event_loop::Event,
+    load_request,
+    loading::{self, LoadRequest, Puzzle, Source},
+    network::{HttpError, Validators},
+    offline,
+    play_state::PlayState,
+    stats::Stats,
+    word_list::{WordList, WordListSource, Words},
+    App, GameMode, Outcome, View,
+};
+
+const WORDS: [&str; 10] = [
+    "CRANE", "SLATE", "TRACE", "SPEED", "EERIE", "FJORD", "GHOST", "LUMPY", "WINDY", "BOXER",
+];
+
+fn words() -> HashSet<String> {
+    WORDS.into_iter().map(String::from).collect()
+}
+
+// the wordle api and the word list server, answering from memory and keeping track of what
+// they were asked
+#[derive(Clone, Default)]
+struct FakeApi {
+    calls: Arc<Mutex<Vec<String>>>,
+    // how many times the daily puzzle fails before it's sent
+    daily_failures: Arc<AtomicUsize>,
+    // sends something that isn't the api's json
+    daily_garbled: bool,
+    word_list_down: bool,
+}
+
+impl FakeApi {
+    fn calls(&self) -> Vec<String> {
+        self.calls.lock().unwrap().clone()
+    }
+}
+
+impl Source for FakeApi {
+    fn daily(&self, date: NaiveDate) -> Result<String> {
+        self.calls.lock().unwrap().push(format!("daily {date}"));
+        let failures = self.daily_failures.load(Ordering::SeqCst);
+        if failures > 0 {
+            self.daily_failures.store(failures - 1, Ordering::SeqCst);
+            return Err(HttpError::Transport {
+                url: String::from("https://www.nytimes.com/svc/wordle/v2"),
+                message: String::from("connection reset"),
+            }
+            .into());
+        }
+        if self.daily_garbled {
+            return Ok(String::from("<html>down for maintenance</html>"));
+        }
+        Ok(format!(
+            r#"{{"solution": "crane", "id": 1, "days_since_launch": 1000, "editor": "someone", "print_date": "{date}"}}"#
+        ))
+    }
+
+    fn word_list(&self, list: &WordListSource, _: Option<&Validators>) -> Result<WordList> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("word list {list:?}"));
+        if self.word_list_down {
+            bail!("word list server is down");
+        }
+        Ok(WordList::Changed(Words::accepted(words()), None))
+    }
+}
+
+// a folder of its own for each game, gone once the test is done with it
+struct Dir(PathBuf);
+
+impl Dir {
+    fn new() -> Self {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let dir = env::temp_dir().join(format!(
+            "wordle-tui-end-to-end-{}-{}",
+            std::process::id(),
+            NEXT.fetch_add(1, Ordering::SeqCst)
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        Self(dir)
+    }
+}
+
+impl Drop for Dir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+fn config(dir: &Dir) -> Config {
+    Config {
+        animations: false,
+        terminal_title: false,
+        confirm_quit: false,
+        autocomplete: false,
+        paths: Paths::in_dir(&dir.0),
+        ..Config::default()
+    }
+}
+
+fn args() -> Args {
+    Args {
+        config: Some(PathBuf::from("/nonexistent/config.toml")),
+        ..Args::default()
+    }
+}
+
+// what launching it with no flags would load
+fn request(config: &Config) -> LoadRequest {
+    let mut request = load_request(&args(), config).unwrap();
+    // tomorrow's puzzle would be asked for too late in the day
+    request.prefetch = false;
+    request
+}
+
+fn date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 3, 14).unwrap()
+}
+
+fn app_for(config: Config, puzzle: Puzzle, date: NaiveDate) -> App {
+    let stats = Stats::load(&config.paths.stats);
+    let args = Args {
+        offline: true,
+        ..args()
+    };
+    App::new(
+        config,
+        GameMode::Daily,
+        puzzle,
+        date,
+        stats,
+        Agent::new(),
+        &args,
+    )
+}
+
+fn app(dir: &Dir, solution: &str) -> App {
+    let puzzle = Puzzle {
+        solution: solution.to_owned(),
+        meta: None,
+        word_list: words(),
+        answers: HashSet::new(),
+    };
+    app_for(config(dir), puzzle, date())
+}
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+// how it was typed, a newline for enter, and then ctrl-c to leave
+fn keys(typed: &str) -> impl Iterator<Item = Event> + '_ {
+    typed
+        .chars()
+        .map(|c| match c {
+            '\n' => key(KeyCode::Enter),
+            '\x08' => key(KeyCode::Backspace),
+            c => key(KeyCode::Char(c)),
+        })
+        .chain([
+            Event::Tick,
+            Event::Key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+        ])
+}
+
+// plays `typed` and returns what was last on screen
+fn play(app: &mut App, typed: &str) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+    app.exit = None;
+    assert_eq!(
+        app.run(&mut terminal, &mut keys(typed)).unwrap(),
+        Outcome::Quit
+    );
+
+    let buffer = terminal.backend().buffer();
+    (0..buffer.area.height)
+        .map(|y| {
+            (0..buffer.area.width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn scores(app: &App) -> Vec<Vec<LetterState>> {
+    app.board()
+        .game
+        .guesses()
+        .iter()
+        .map(|guess| guess.letters().map(|(_, state)| state).collect())
+        .collect()
+}
+
+#[test]
+fn typed_guesses_are_scored_until_the_word_is_found() {
+    let dir = Dir::new();
+    let mut app = app(&dir, "CRANE");
+    play(&mut app, "slate\ntrace\ncrane\n");
+
+    assert_eq!(
+        scores(&app),
+        [[A, A, C, A, C], [A, C, C, P, C], [C; 5]].map(Vec::from)
+    );
+    assert_eq!(app.board().game.status(), GameStatus::Won);
+    assert!(matches!(app.view, View::Results { .. }));
+    assert!(app.stats.records()[0].won);
+}
+
+#[test]
+fn letters_can_be_taken_back_before_enter() {
+    let dir = Dir::new();
+    let mut app = app(&dir, "CRANE");
+    play(&mut app, "crank\x08e\n");
+
+    assert_eq!(app.board().game.guesses()[0].word, "CRANE");
+    assert_eq!(app.board().game.status(), GameStatus::Won);
+}
+
+#[test]
+fn rejected_guesses_leave_the_board_alone() {
+    let short_dir = Dir::new();
+    let mut short = app(&short_dir, "CRANE");
+    let screen = play(&mut short, "slat\n");
+    assert!(screen.contains("not enough letters"), "{screen}");
+
+    let typo_dir = Dir::new();
+    let mut typo = app(&typo_dir, "CRANE");
+    let screen = play(&mut typo, "crabe\n");
+    assert!(
+        screen.contains("not in word list, did you mean CRANE?"),
+        "{screen}"
+    );
+    assert!(typo.board().game.guesses().is_empty());
+    // what was typed stays for fixing
+    assert_eq!(typo.input.to_uppercase(), "CRABE");
+}
+
+#[test]
+fn repeated_letters_are_only_marked_as_often_as_the_answer_has_them() {
+    let dir = Dir::new();
+    let mut app = app(&dir, "SPEED");
+    play(&mut app, "eerie\n");
+
+    // the third e has no e left in the answer to be yellow for
+    assert_eq!(scores(&app), [vec![P, P, A, A, A]]);
+}
+
+#[test]
+fn six_misses_lose_and_give_the_answer() {
+    let dir = Dir::new();
+    let mut app = app(&dir, "CRANE");
+    let screen = play(&mut app, "fjord\nghost\nlumpy\nwindy\nboxer\nspeed\n");
+
+    assert_eq!(app.board().game.status(), GameStatus::Lost);
+    assert!(matches!(app.view, View::Results { .. }));
+    assert!(screen.contains("CRANE"), "{screen}");
+    assert!(!app.stats.records()[0].won);
+}
+
+#[test]
+fn an_unfinished_daily_carries_on_where_it_was_left() {
+    let dir = Dir::new();
+    let config = config(&dir);
+    let mut first = app(&dir, "CRANE");
+    play(&mut first, "slate\n");
+    first.save_daily("CRANE", None).unwrap();
+
+    let saved = PlayState::load(&config.paths.play_state).unwrap();
+    assert!(saved.is_for("CRANE") && saved.share.is_none());
+    let mut second = app(&dir, "CRANE");
+    second.resume(&saved);
+    assert_eq!(second.board().game.guesses()[0].word, "SLATE");
+
+    play(&mut second, "crane\n");
+    let share = second.share_text();
+    second.save_daily("CRANE", Some(share)).unwrap();
+
+    let saved = PlayState::load(&config.paths.play_state).unwrap();
+    assert!(saved.share.is_some());
+    assert_eq!(saved.guesses, ["SLATE", "CRANE"]);
+    let stats = Stats::load(&config.paths.stats);
+    let records = stats.records();
+    assert_eq!(records.len(), 1);
+    assert!(records[0].won && records[0].guesses == 2);
+}
+
+#[test]
+fn the_daily_puzzle_is_fetched_once_and_played() {
+    let dir = Dir::new();
+    let config = config(&dir);
+    let api = FakeApi::default();
+    let request = request(&config);
+    assert_eq!(request.mode, GameMode::Daily);
+
+    let puzzle = loading::load(&request, &api, &|_| {}).unwrap();
+    assert!(puzzle.solution.eq_ignore_ascii_case("CRANE"));
+    assert_eq!(puzzle.meta.as_ref().map(|meta| meta.number), Some(1000));
+    assert_eq!(
+        api.calls(),
+        [
+            format!("daily {}", request.date),
+            String::from("word list Nyt")
+        ]
+    );
+
+    // both are cached now, the second launch doesn't ask for anything
+    let again = loading::load(&request, &api, &|_| {}).unwrap();
+    assert_eq!(again.solution, puzzle.solution);
+    assert_eq!(api.calls().len(), 2);
+
+    let mut app = app_for(config, puzzle, request.date);
+    play(&mut app, "trace\ncrane\n");
+    assert_eq!(app.board().game.status(), GameStatus::Won);
+    assert_eq!(app.stats.records()[0].guesses, 2);
+}
+
+#[test]
+fn a_failed_request_is_tried_again() {
+    let dir = Dir::new();
+    let api = FakeApi::default();
+    api.daily_failures.store(1, Ordering::SeqCst);
+    let mut request = request(&config(&dir));
+    request.attempts = 2;
+
+    let reports = Mutex::new(Vec::new());
+    let puzzle = loading::load(&request, &api, &|message| {
+        reports.lock().unwrap().push(message.to_owned());
+    })
+    .unwrap();
+    assert!(puzzle.solution.eq_ignore_ascii_case("CRANE"));
+    let daily = format!("daily {}", request.date);
+    assert_eq!(api.calls()[..2], [daily.clone(), daily]);
+    assert!(reports
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|message| message.starts_with("request failed, retrying")));
+}
+
+#[test]
+fn an_unreadable_response_is_not_asked_for_again() {
+    let dir = Dir::new();
+    let api = FakeApi {
+        daily_garbled: true,
+        ..FakeApi::default()
+    };
+    let mut request = request(&config(&dir));
+    request.attempts = 3;
+
+    let err = loading::load(&request, &api, &|_| {}).unwrap_err();
+    assert!(
+        format!("{err:#}").contains("unexpected response"),
+        "{err:#}"
+    );
+    assert_eq!(api.calls(), [format!("daily {}", request.date)]);
+}
+
+#[test]
+fn the_bundled_word_list_stands_in_when_the_download_fails() {
+    let dir = Dir::new();
+    let api = FakeApi {
+        word_list_down: true,
+        ..FakeApi::default()
+    };
+    let mut request = request(&config(&dir));
+    request.attempts = 1;
+
+    let puzzle = loading::load(&request, &api, &|_| {}).unwrap();
+    // far more words than the fake would have sent
+    assert!(puzzle.word_list.len() > WORDS.len());
+}
+
+#[test]
+fn offline_dailies_come_from_the_answers() {
+    let dir = Dir::new();
+    let mut request = request(&config(&dir));
+    let pool = answers::pool(&offline::word_list(), &HashSet::new(), request.answer_pool);
+
+    for day in 0..7 {
+        request.date = date() + Days::new(day);
+        let puzzle = Puzzle::offline(&request).unwrap();
+        assert!(pool.contains(&puzzle.solution), "{}", puzzle.solution);
+    }
+}
+
+#[test]
+fn a_daemon_started_on_a_finished_daily_waits_and_can_be_quit() {
+    let dir = Dir::new();
+    let config = config(&dir);
+    let mut request = request(&config);
+    request.date = config.timezone.today();
+    let puzzle = || Puzzle {
+        solution: String::from("CRANE"),
+        meta: None,
+        word_list: words(),
+        answers: HashSet::new(),
+    };
+
+    let mut first = app_for(config.clone(), puzzle(), request.date);
+    play(&mut first, "crane\n");
+    first.save_daily("CRANE", Some(first.share_text())).unwrap();
+
+    // the countdown comes up rather than the board, and ctrl-c there ends it for good
+    let args = Args {
+        offline: true,
+        daemon: true,
+        wait: true,
+        ..args()
+    };
+    let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+    let outcome = crate::play(
+        &mut terminal,
+        &mut keys(""),
+        config,
+        puzzle(),
+        &request,
+        &args,
+    );
+    assert_eq!(outcome.unwrap(), Outcome::Quit);
+    let screen = format!("{:?}", terminal.backend().buffer());
+    assert!(screen.contains("next puzzle in"), "{screen}");
+}
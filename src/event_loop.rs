@@ -0,0 +1,65 @@
+use anyhow::{Context, Result};
+use crossterm::event::{self, KeyEvent, KeyEventKind, MouseEvent};
+use std::time::{Duration, Instant};
+
+// what every screen's loop waits on, presses to handle or the tick that keeps animations,
+// timers and countdowns moving while nothing is pressed
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Event {
+    Key(KeyEvent),
+    Mouse(MouseEvent),
+    // everything pasted at once, rather than a key press per character
+    Paste(String),
+    Tick,
+}
+
+pub trait Events {
+    // blocks until there's something to do
+    fn next(&mut self) -> Result<Event>;
+}
+
+// the real terminal, ticking every `tick_rate` however much is being typed
+#[derive(Debug)]
+pub struct Crossterm {
+    tick_rate: Duration,
+    next_tick: Instant,
+}
+
+impl Crossterm {
+    pub fn new(tick_rate: Duration) -> Self {
+        Self {
+            tick_rate,
+            next_tick: Instant::now() + tick_rate,
+        }
+    }
+}
+
+impl Events for Crossterm {
+    fn next(&mut self) -> Result<Event> {
+        loop {
+            let timeout = self.next_tick.saturating_duration_since(Instant::now());
+            if !event::poll(timeout)? {
+                self.next_tick = Instant::now() + self.tick_rate;
+                return Ok(Event::Tick);
+            }
+
+            match event::read()? {
+                // releases and repeats are reported too on some platforms
+                event::Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+                    return Ok(Event::Key(key_event));
+                }
+                event::Event::Mouse(mouse_event) => return Ok(Event::Mouse(mouse_event)),
+                event::Event::Paste(text) => return Ok(Event::Paste(text)),
+                // a resize is redrawn on the next tick anyway
+                _ => {}
+            }
+        }
+    }
+}
+
+// anything that lists events out can stand in for the terminal, like a script of key presses
+impl<I: Iterator<Item = Event>> Events for I {
+    fn next(&mut self) -> Result<Event> {
+        Iterator::next(self).context("ran out of events")
+    }
+}
@@ -0,0 +1,27 @@
+use serde_json::{json, Value};
+use wordle_tui::wordle_core::{Game, GameStatus, LetterState};
+
+// one board's side of an exported game, the solution is fine to include once it's over.
+// without `words` the solution and guesses are left out (null) and only the colors are kept
+pub fn game_json(game: &Game, words: bool) -> Value {
+    json!({
+        "solution": words.then(|| game.solution()),
+        "won": game.status() == GameStatus::Won,
+        "guesses": game
+            .guesses()
+            .iter()
+            .map(|guess| json!({
+                "word": words.then_some(&guess.word),
+                "result": guess.states.iter().copied().map(state_name).collect::<Vec<&str>>(),
+            }))
+            .collect::<Vec<Value>>(),
+    })
+}
+
+pub const fn state_name(state: LetterState) -> &'static str {
+    match state {
+        LetterState::Correct => "correct",
+        LetterState::Present => "present",
+        LetterState::Absent => "absent",
+    }
+}
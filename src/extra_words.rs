@@ -0,0 +1,249 @@
+use anyhow::{bail, Context, Result};
+use std::{
+    collections::{BTreeSet, HashSet},
+    fs,
+    iter::{self, Peekable},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    loading::LoadRequest,
+    storage,
+    word_list::{self, Cache},
+};
+
+const HEADER: &str =
+    "# words accepted on top of the word list, and ones taken out of it with a - in \
+                      front. see `wordle-tui wordlist`";
+
+// words a group allows, or doesn't, on top of whichever word list is used. only guesses are
+// affected, answers still come from the list itself
+#[derive(Debug, Default)]
+pub struct ExtraWords {
+    added: BTreeSet<String>,
+    removed: BTreeSet<String>,
+}
+
+fn is_word(word: &str) -> bool {
+    !word.is_empty() && word.chars().all(char::is_alphabetic)
+}
+
+impl ExtraWords {
+    // lines that aren't words are skipped, `wordlist validate` points them out
+    pub fn load(path: &Path) -> Self {
+        let mut extra = Self::default();
+        for line in fs::read_to_string(path).unwrap_or_default().lines() {
+            let line = line.trim().to_uppercase();
+            match line.strip_prefix('-') {
+                Some(word) if is_word(word) => {
+                    extra.removed.insert(word.to_owned());
+                }
+                _ if is_word(&line) => {
+                    extra.added.insert(line);
+                }
+                _ => {}
+            }
+        }
+        extra
+    }
+
+    fn save(&self, path: &Path) -> Result<()> {
+        let lines = iter::once(HEADER.to_owned())
+            .chain(self.added.iter().cloned())
+            .chain(self.removed.iter().map(|word| format!("-{word}")))
+            .collect::<Vec<String>>();
+        storage::write(path, lines.join("\n") + "\n")
+    }
+
+    // the solution is never taken out, a removed word can still be today's answer
+    pub fn apply(&self, accepted: &mut HashSet<String>, solution: &str, word_length: usize) {
+        accepted.extend(
+            self.added
+                .iter()
+                .filter(|word| word.chars().count() == word_length)
+                .cloned(),
+        );
+        let solution = solution.to_uppercase();
+        accepted.retain(|word| *word == solution || !self.removed.contains(word));
+    }
+
+    // returns whether it wasn't already accepted
+    fn add(&mut self, word: String) -> bool {
+        self.removed.remove(&word) | self.added.insert(word)
+    }
+
+    fn remove(&mut self, word: String) -> bool {
+        if self.added.remove(&word) {
+            return true;
+        }
+        self.removed.insert(word)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Command {
+    ShowPath,
+    Validate,
+    Add(Vec<String>),
+    Remove(Vec<String>),
+    Import(PathBuf),
+}
+
+impl Command {
+    // everything after `wordlist`, up to the first flag
+    pub fn parse(raw_args: &mut Peekable<impl Iterator<Item = String>>) -> Result<Self> {
+        let command = raw_args
+            .next()
+            .context("wordlist needs show-path, validate, add, remove or import")?;
+        let mut words = || {
+            let mut words = Vec::new();
+            while let Some(word) = raw_args.next_if(|arg| !arg.starts_with('-')) {
+                if !is_word(&word) {
+                    bail!("{word} isn't a word");
+                }
+                words.push(word.to_uppercase());
+            }
+            if words.is_empty() {
+                bail!("wordlist {command} needs a word");
+            }
+            Ok(words)
+        };
+
+        Ok(match command.as_str() {
+            "show-path" => Self::ShowPath,
+            "validate" => Self::Validate,
+            "add" => Self::Add(words()?),
+            "remove" => Self::Remove(words()?),
+            "import" => Self::Import(
+                raw_args
+                    .next()
+                    .context("wordlist import needs a file")?
+                    .into(),
+            ),
+            other => bail!(
+                "unknown wordlist command {other}, expected show-path, validate, add, remove or \
+                 import"
+            ),
+        })
+    }
+}
+
+// the list the extra words go on top of, as it is on disk. downloaded lists are only looked at
+// in their cache, these commands don't go online
+fn base_words(request: &LoadRequest) -> Result<HashSet<String>> {
+    match request.word_list.cache_path(&request.word_list_cache) {
+        Some(cache_path) => Cache::load(&cache_path)
+            .map(|cache| cache.words.accepted)
+            .with_context(|| {
+                format!(
+                    "there's no cached word list at {} yet, play a game first",
+                    cache_path.display()
+                )
+            }),
+        None => Ok(request.word_list.fetch_words(&request.agent)?.accepted),
+    }
+}
+
+pub fn run(command: Command, request: &LoadRequest) -> Result<()> {
+    let path = &request.word_list_extensions;
+    let mut extra = ExtraWords::load(path);
+    match command {
+        Command::ShowPath => {
+            println!("extra words: {}", path.display());
+            match request.word_list.cache_path(&request.word_list_cache) {
+                Some(cache_path) => println!("word list cache: {}", cache_path.display()),
+                None => println!(
+                    "word list cache: none, {:?} isn't cached",
+                    request.word_list
+                ),
+            }
+        }
+        Command::Validate => validate(&extra, path, &base_words(request)?)?,
+        Command::Add(words) => {
+            for word in words {
+                if extra.add(word.clone()) {
+                    println!("added {word}");
+                } else {
+                    println!("{word} was already added");
+                }
+            }
+            extra.save(path)?;
+        }
+        Command::Remove(words) => {
+            for word in words {
+                if extra.remove(word.clone()) {
+                    println!("removed {word}");
+                } else {
+                    println!("{word} was already removed");
+                }
+            }
+            extra.save(path)?;
+        }
+        Command::Import(file) => {
+            let contents = fs::read_to_string(&file)
+                .with_context(|| format!("failed to read {}", file.display()))?;
+            let words = word_list::parse(&contents);
+            let total = words.len();
+            let new = words
+                .into_iter()
+                .filter(|word| extra.add(word.clone()))
+                .count();
+            println!("imported {new} words, {} were already added", total - new);
+            extra.save(path)?;
+        }
+    }
+
+    Ok(())
+}
+
+// everything that'd be skipped or do nothing, by line
+fn validate(extra: &ExtraWords, path: &Path, base: &HashSet<String>) -> Result<()> {
+    let mut problems = Vec::new();
+    let mut seen = HashSet::new();
+    for (i, line) in fs::read_to_string(path)
+        .unwrap_or_default()
+        .lines()
+        .enumerate()
+    {
+        let line = line.trim().to_uppercase();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (word, removed) = line
+            .strip_prefix('-')
+            .map_or((line.as_str(), false), |word| (word, true));
+
+        let problem = if !is_word(word) {
+            "isn't a word, it's skipped"
+        } else if !seen.insert(word.to_owned()) {
+            "is listed more than once"
+        } else if removed && !base.contains(word) {
+            "is removed but isn't in the word list"
+        } else if !removed && base.contains(word) {
+            "is added but is in the word list already"
+        } else {
+            continue;
+        };
+        problems.push(format!("line {}: {word} {problem}", i + 1));
+    }
+
+    println!(
+        "{} words added and {} removed, on top of {} in the word list",
+        extra.added.len(),
+        extra.removed.len(),
+        base.len()
+    );
+    for problem in &problems {
+        println!("{problem}");
+    }
+    if !problems.is_empty() {
+        bail!(
+            "{} problem{} in {}",
+            problems.len(),
+            if problems.len() == 1 { "" } else { "s" },
+            path.display()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anyhow::Result;
+use serde_json::json;
+use std::io::{self, BufRead};
+use wordle_tui::wordle_core::{Game, GameStatus, Guess, GuessError, LetterState};
+
+use crate::{export::state_name, loading::Puzzle};
+
+// one guess per line on stdin, one result per line on stdout, until the game is over
+pub fn run(puzzle: &Puzzle, hard_mode: bool, json: bool) -> Result<()> {
+    let mut game = Game::new(&puzzle.solution, hard_mode);
+
+    for line in io::stdin().lock().lines() {
+        let line = line?;
+        let guess = line.trim();
+        if guess.is_empty() {
+            continue;
+        }
+
+        match game.submit(guess, &puzzle.word_list) {
+            Ok(scored) => println!("{}", guess_line(scored, json)),
+            Err(err) => println!("{}", error_line(guess, err, json)),
+        }
+
+        if game.status() != GameStatus::InProgress {
+            break;
+        }
+    }
+
+    println!("{}", summary_line(&game, json));
+    Ok(())
+}
+
+// CRANE G.Y.. reads as green C, absent R, yellow A and so on
+fn guess_line(guess: &Guess, json: bool) -> String {
+    if json {
+        return json!({
+            "guess": guess.word,
+            "result": guess.states.iter().copied().map(state_name).collect::<Vec<&str>>(),
+        })
+        .to_string();
+    }
+
+    let result = guess
+        .states
+        .iter()
+        .map(|state| match state {
+            LetterState::Correct => 'G',
+            LetterState::Present => 'Y',
+            LetterState::Absent => '.',
+        })
+        .collect::<String>();
+    format!("{} {result}", guess.word)
+}
+
+fn error_line(guess: &str, err: GuessError, json: bool) -> String {
+    if json {
+        json!({ "guess": guess.to_uppercase(), "error": err.to_string() }).to_string()
+    } else {
+        format!("{} error: {err}", guess.to_uppercase())
+    }
+}
+
+// the solution is only given away once there's nothing left to guess
+fn summary_line(game: &Game, json: bool) -> String {
+    let status = game.status();
+    let guesses = game.guesses().len();
+
+    if json {
+        return json!({
+            "status": match status {
+                GameStatus::InProgress => "in_progress",
+                GameStatus::Won => "won",
+                GameStatus::Lost => "lost",
+            },
+            "guesses": guesses,
+            "solution": (status != GameStatus::InProgress).then(|| game.solution()),
+        })
+        .to_string();
+    }
+
+    match status {
+        GameStatus::Won => format!("won in {guesses}/{}", game.max_guesses()),
+        GameStatus::Lost => format!("lost, the word was {}", game.solution()),
+        GameStatus::InProgress => format!("gave up after {guesses}/{}", game.max_guesses()),
+    }
+}
@@ -0,0 +1,144 @@
+use ratatui::{
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Clear, Padding, Paragraph},
+    Frame,
+};
+use std::fmt::Display;
+use wordle_tui::wordle_core::LetterState;
+
+use crate::config::Config;
+
+pub const WIDTH: u16 = 60;
+
+fn key_line(key: impl Display, action: &str) -> Line<'static> {
+    Line::from(vec![
+        Span::from(format!("  {:<15} ", key.to_string())).bold(),
+        Span::from(action.to_owned()),
+    ])
+}
+
+// returns how far it's scrolled, see `scrolled`
+pub fn render(config: &Config, frame: &mut Frame, scroll: u16) -> u16 {
+    let keys = &config.keys;
+    let color_line = |state: LetterState, meaning: &str| {
+        Line::from(vec![
+            Span::from("  "),
+            config.colors.letter('A', Some(state)).bold(),
+            Span::from(format!(
+                "{:width$} {meaning}",
+                "",
+                width = if config.colors.symbols { 12 } else { 14 }
+            )),
+        ])
+    };
+
+    let mut lines = vec![
+        Line::from("keys").bold(),
+        key_line(&keys.submit, "submit a guess"),
+        key_line(&keys.backspace, "delete a letter"),
+        key_line(&keys.clear_input, "delete the whole guess"),
+        key_line(&keys.toggle_hard_mode, "toggle hard mode before guessing"),
+        key_line(&keys.copy, "copy results when the game ends"),
+        key_line(&keys.new_round, "next word in practice mode"),
+        key_line(&keys.assist, "show or hide suggested words"),
+        key_line(&keys.complete, "finish the guess, ↑/↓ for other words"),
+        key_line(&keys.letter_frequencies, "show or hide letter frequencies"),
+        key_line(&keys.hint, "rule out letters or reveal one, a few per game"),
+        key_line(&keys.stats, "stats and calendar after a daily game"),
+        key_line(&keys.analysis, "how much each guess narrowed it down"),
+        key_line(&keys.history, "every game played, with replays"),
+        key_line(&keys.achievements, "achievements unlocked and still to go"),
+        key_line(&keys.leaderboard, "friends' results for today"),
+        key_line(&keys.settings, "settings, from here or the results"),
+        key_line(&keys.scroll_down, "scroll popups and the history"),
+        key_line(&keys.scroll_up, "scroll back up"),
+        key_line(
+            format!("{}, {}", keys.top, keys.bottom),
+            "jump to the top or bottom",
+        ),
+        key_line(&keys.help, "show or hide this help"),
+        key_line(&keys.close, "close a popup or the results"),
+        key_line(&keys.quit, "quit"),
+        Line::default(),
+        Line::from("colors").bold(),
+        color_line(LetterState::Correct, "right letter, right spot"),
+        color_line(LetterState::Present, "in the word, wrong spot"),
+        color_line(LetterState::Absent, "not in the word"),
+        Line::default(),
+        Line::from("hard mode").bold(),
+        Line::from("  green letters have to stay where they are"),
+        Line::from("  yellow letters have to be used again"),
+    ];
+    if keys.modal {
+        lines.splice(
+            1..1,
+            [
+                key_line(&keys.insert, "start typing letters"),
+                key_line(&keys.normal, "stop typing, for the keys below"),
+            ],
+        );
+    }
+
+    let (area, scroll) = scrolled(frame.area(), lines.len(), scroll);
+
+    let help = Paragraph::new(lines).scroll((scroll, 0)).block(
+        Block::bordered()
+            .title(" help ")
+            .title_bottom(Line::from(format!(" {} to close ", keys.close.primary())).centered())
+            .border_style(Style::default().fg(Color::DarkGray))
+            .padding(Padding::horizontal(1)),
+    );
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(help, area);
+    scroll
+}
+
+// asked before leaving a game halfway through, daily puzzles pick up where they were left
+pub fn render_quit(config: &Config, saved: bool, frame: &mut Frame) {
+    let lines = vec![
+        Line::from("quit?").bold(),
+        Line::from(if saved {
+            "your progress will be saved"
+        } else {
+            "your progress will be lost"
+        }),
+    ];
+
+    let area = centered(frame.area(), 34, 4);
+    let popup = Paragraph::new(lines).centered().block(
+        Block::bordered()
+            .title_bottom(
+                Line::from(format!(
+                    " y to quit, n or {} to stay ",
+                    config.keys.close.primary()
+                ))
+                .centered(),
+            )
+            .border_style(Style::default().fg(Color::DarkGray)),
+    );
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
+}
+
+// where a popup with this many lines goes. one taller than the terminal is cut to fit and
+// scrolled, so the scroll is kept to what's left to show
+pub fn scrolled(area: Rect, lines: usize, scroll: u16) -> (Rect, u16) {
+    let height = u16::try_from(lines + 2).unwrap_or(u16::MAX);
+    let popup = centered(area, WIDTH, height.min(area.height));
+    (popup, scroll.min(height - popup.height))
+}
+
+pub fn centered(area: Rect, width: u16, height: u16) -> Rect {
+    let [area] = Layout::vertical([Constraint::Length(height)])
+        .flex(Flex::Center)
+        .areas(area);
+    let [area] = Layout::horizontal([Constraint::Length(width)])
+        .flex(Flex::Center)
+        .areas(area);
+
+    area
+}
@@ -0,0 +1,71 @@
+use ratatui::{style::Stylize, text::Line};
+use std::collections::HashSet;
+use wordle_tui::wordle_core::Hint;
+
+// every letter the word list uses, so only letters that could be in the answer are ruled out
+pub fn alphabet(word_list: &HashSet<String>) -> HashSet<char> {
+    word_list.iter().flat_map(|word| word.chars()).collect()
+}
+
+// what the hint gave away, as a notice
+pub fn describe(hint: &Hint) -> String {
+    match hint {
+        Hint::Letter { index, letter } => format!("letter {} is {letter}", index + 1),
+        Hint::Absent(letters) => format!("no {}", spaced(letters)),
+    }
+}
+
+fn spaced(letters: &[char]) -> String {
+    letters
+        .iter()
+        .map(char::to_string)
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+// "2A" for a revealed letter, "-QXZ" for ruled out ones, as kept in the play state
+pub fn encode(hint: &Hint) -> String {
+    match hint {
+        Hint::Letter { index, letter } => format!("{index}{letter}"),
+        Hint::Absent(letters) => format!("-{}", letters.iter().collect::<String>()),
+    }
+}
+
+pub fn decode(encoded: &str) -> Option<Hint> {
+    if let Some(letters) = encoded.strip_prefix('-') {
+        return Some(Hint::Absent(letters.chars().collect()));
+    }
+
+    let letter = encoded.chars().last()?;
+    let index = encoded[..encoded.len() - letter.len_utf8()].parse().ok()?;
+    Some(Hint::Letter { index, letter })
+}
+
+// "hints: ··A·· no Q X Z, 1 left" under the title, so what's been given away stays in view
+pub fn summary(hints: &[Hint], word_length: usize, left: usize) -> Line<'static> {
+    let mut positions = vec!['·'; word_length];
+    let mut absent = Vec::new();
+    for hint in hints {
+        match hint {
+            Hint::Letter { index, letter } => {
+                if let Some(position) = positions.get_mut(*index) {
+                    *position = *letter;
+                }
+            }
+            Hint::Absent(letters) => absent.extend(letters),
+        }
+    }
+    absent.sort_unstable();
+
+    let mut summary = String::from("hints:");
+    if positions.iter().any(|&position| position != '·') {
+        summary.push(' ');
+        summary.extend(positions);
+    }
+    if !absent.is_empty() {
+        summary.push_str(" no ");
+        summary.push_str(&spaced(&absent));
+    }
+
+    Line::from(format!("{summary}, {left} left")).dark_gray()
+}
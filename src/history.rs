@@ -0,0 +1,258 @@
+use anyhow::Result;
+use ratatui::{
+    backend::Backend,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{Block, List, ListItem, ListState, Padding, Paragraph},
+    Frame, Terminal,
+};
+use std::time::{Duration, Instant};
+use wordle_tui::wordle_core::{Game, MAX_GUESSES};
+
+use crate::{
+    board::{Board, TILE_HEIGHT},
+    config::Config,
+    event_loop::{Event, Events},
+    keymap::{Action, Pending},
+    stats::{GameRecord, Stats},
+    timer,
+};
+
+// how long each tile takes to flip in a replay
+const REPLAY_TILE_DELAY: Duration = Duration::from_millis(120);
+
+// games played before guesses were kept can't be replayed
+struct Replay<'a> {
+    record: &'a GameRecord,
+    answer: &'a str,
+    started: Instant,
+}
+
+// every recorded game, newest first. returns whether the player quit outright rather than
+// going back to wherever this was opened from
+pub fn run(
+    terminal: &mut Terminal<impl Backend>,
+    events: &mut impl Events,
+    config: &Config,
+    stats: &Stats,
+) -> Result<bool> {
+    let records = stats.records().iter().rev().collect::<Vec<&GameRecord>>();
+    let mut selected = 0_usize;
+    let mut replay: Option<Replay> = None;
+    let mut message = None;
+    let mut pending = Pending::default();
+
+    loop {
+        terminal.draw(|frame| match &replay {
+            Some(replay) => draw_replay(frame, config, replay),
+            None => draw_list(frame, config, &records, selected, message),
+        })?;
+
+        let Event::Key(key_event) = events.next()? else {
+            continue;
+        };
+
+        let keys = &config.keys;
+        let action = pending.action(
+            keys,
+            key_event,
+            &[
+                Action::Quit,
+                Action::Submit,
+                Action::Close,
+                Action::ScrollUp,
+                Action::ScrollDown,
+                Action::Top,
+                Action::Bottom,
+            ],
+        );
+        if action == Some(Action::Quit) {
+            return Ok(true);
+        }
+
+        if replay.is_some() {
+            if action == Some(Action::Close) {
+                replay = None;
+            }
+            continue;
+        }
+
+        message = None;
+        match action {
+            Some(Action::Submit) => {
+                let Some(&record) = records.get(selected) else {
+                    continue;
+                };
+                match &record.answer {
+                    Some(answer) if !record.words.is_empty() => {
+                        replay = Some(Replay {
+                            record,
+                            answer,
+                            started: Instant::now(),
+                        });
+                    }
+                    _ => message = Some("this game was played before replays were kept"),
+                }
+            }
+            Some(Action::Close) => return Ok(false),
+            Some(Action::ScrollUp) => selected = selected.saturating_sub(1),
+            Some(Action::ScrollDown) => {
+                selected = (selected + 1).min(records.len().saturating_sub(1));
+            }
+            Some(Action::Top) => selected = 0,
+            Some(Action::Bottom) => selected = records.len().saturating_sub(1),
+            _ => {}
+        }
+    }
+}
+
+// 2025-06-01  won   4/6  1:23
+fn record_line(record: &GameRecord) -> String {
+    let result = if record.won {
+        format!("won   {}/{MAX_GUESSES}", record.guesses)
+    } else {
+        format!("lost  X/{MAX_GUESSES}")
+    };
+    let duration = record
+        .duration
+        .map(|duration| format!("  {}", timer::format(duration)))
+        .unwrap_or_default();
+
+    format!("{}  {result}{duration}", record.date.format("%Y-%m-%d"))
+}
+
+fn draw_list(
+    frame: &mut Frame,
+    config: &Config,
+    records: &[&GameRecord],
+    selected: usize,
+    message: Option<&str>,
+) {
+    let [list_area, message_area, hint_area] = Layout::vertical([
+        Constraint::Min(1),
+        Constraint::Length(1),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+    let [list_area] = Layout::horizontal([Constraint::Length(40)])
+        .flex(ratatui::layout::Flex::Center)
+        .areas(list_area);
+
+    let items = records
+        .iter()
+        .map(|record| ListItem::new(record_line(record)))
+        .collect::<Vec<ListItem>>();
+    let list = List::new(items)
+        .block(
+            Block::bordered()
+                .title(
+                    Line::from(match records.len() {
+                        1 => String::from(" 1 game "),
+                        count => format!(" {count} games "),
+                    })
+                    .centered(),
+                )
+                .border_style(Style::default().fg(Color::DarkGray))
+                .padding(Padding::horizontal(1)),
+        )
+        .highlight_style(Style::default().fg(config.colors.title).bold())
+        .highlight_symbol("> ");
+
+    // the list scrolls itself to keep the selection in view
+    let mut state = ListState::default().with_selected(Some(selected));
+    frame.render_stateful_widget(list, list_area, &mut state);
+
+    if let Some(message) = message {
+        frame.render_widget(
+            Paragraph::new(message)
+                .style(Style::default().fg(Color::LightRed))
+                .centered(),
+            message_area,
+        );
+    }
+
+    let keys = &config.keys;
+    let hint = format!(
+        "{}/{} to pick a game, {} to replay it, {} to go back",
+        keys.scroll_up.primary(),
+        keys.scroll_down.primary(),
+        keys.submit.primary(),
+        keys.close.primary()
+    );
+    frame.render_widget(
+        Paragraph::new(hint)
+            .style(Style::default().fg(Color::DarkGray).dim())
+            .centered(),
+        hint_area,
+    );
+}
+
+// the guesses go down again one tile at a time, as they were played
+fn draw_replay(frame: &mut Frame, config: &Config, replay: &Replay) {
+    let [title_area, board_area, hint_area] = Layout::vertical([
+        Constraint::Length(2),
+        Constraint::Min(1),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    #[allow(clippy::cast_possible_truncation)]
+    let tile = (replay.started.elapsed().as_millis() / REPLAY_TILE_DELAY.as_millis()) as usize;
+    let word_length = replay.answer.chars().count().max(1);
+    let row = tile / word_length;
+    let words = &replay.record.words;
+    let board = Board::new(Game::restore(
+        replay.answer,
+        false,
+        MAX_GUESSES,
+        &words[..(row + 1).min(words.len())],
+    ));
+
+    frame.render_widget(
+        Paragraph::new(record_line(replay.record))
+            .style(Style::default().fg(config.colors.title))
+            .centered(),
+        title_area,
+    );
+    let tiles = board.render_tiles(
+        frame,
+        board_area,
+        &config.colors,
+        config.double_letters,
+        |r| {
+            if r == row {
+                tile % word_length + 1
+            } else {
+                usize::MAX
+            }
+        },
+    );
+
+    // how long each guess took, beside its row once it's flipped
+    let flipped = if tile % word_length + 1 >= word_length {
+        row + 1
+    } else {
+        row
+    };
+    for (r, think_time) in replay.record.think_times.iter().enumerate().take(flipped) {
+        let Some(tiles) = tiles else {
+            break;
+        };
+        #[allow(clippy::cast_possible_truncation)]
+        let note = Rect {
+            x: tiles.right() + 1,
+            y: tiles.y + r as u16 * TILE_HEIGHT + TILE_HEIGHT / 2,
+            width: 8,
+            height: 1,
+        }
+        .intersection(frame.area());
+        frame.render_widget(Paragraph::new(timer::format(*think_time)).dark_gray(), note);
+    }
+    frame.render_widget(
+        Paragraph::new(format!("{} to go back", config.keys.close.primary()))
+            .style(Style::default().fg(Color::DarkGray).dim())
+            .centered(),
+        hint_area,
+    );
+}
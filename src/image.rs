@@ -0,0 +1,101 @@
+use ratatui::style::Color;
+use std::fmt::Write;
+use wordle_tui::wordle_core::{Game, LetterState};
+
+use crate::config::Colors;
+
+const TILE: usize = 56;
+const GAP: usize = 6;
+const PADDING: usize = 16;
+const TITLE_HEIGHT: usize = 40;
+// more boards than this go on another line
+const BOARDS_PER_LINE: usize = 4;
+const BOARD_GAP: usize = 24;
+
+const BACKGROUND: &str = "#121213";
+const TEXT: &str = "#ffffff";
+
+// terminal colors have no fixed shade, so anything not given as rgb is drawn in the nyt's
+fn hex(color: Color, fallback: &str) -> String {
+    match color {
+        Color::Rgb(r, g, b) => format!("#{r:02x}{g:02x}{b:02x}"),
+        _ => fallback.to_owned(),
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+// the finished boards as an svg, for posting where the emoji grid comes out badly. without
+// `letters` only the colors are drawn, like the share text
+pub fn svg(title: &str, games: &[&Game], colors: &Colors, letters: bool) -> String {
+    let fills = [
+        hex(colors.correct, "#6aaa64"),
+        hex(colors.wrong_placement, "#c9b458"),
+        hex(colors.absent, "#3a3a3c"),
+    ];
+    let fill = |state| match state {
+        LetterState::Correct => &fills[0],
+        LetterState::Present => &fills[1],
+        LetterState::Absent => &fills[2],
+    };
+
+    let word_length = games.first().map_or(0, |game| game.word_length());
+    let rows = games
+        .iter()
+        .map(|game| game.guesses().len())
+        .max()
+        .unwrap_or(0);
+    let board_width = (word_length * (TILE + GAP)).saturating_sub(GAP);
+    let board_height = (rows * (TILE + GAP)).saturating_sub(GAP);
+    let columns = games.len().clamp(1, BOARDS_PER_LINE);
+    let lines = games.len().div_ceil(BOARDS_PER_LINE).max(1);
+
+    let width = PADDING * 2 + columns * (board_width + BOARD_GAP) - BOARD_GAP;
+    let height = PADDING * 2 + TITLE_HEIGHT + lines * (board_height + BOARD_GAP) - BOARD_GAP;
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\" font-family=\"Helvetica, Arial, sans-serif\">\n\
+         <rect width=\"100%\" height=\"100%\" fill=\"{BACKGROUND}\"/>\n\
+         <text x=\"{}\" y=\"{}\" fill=\"{TEXT}\" font-size=\"24\" font-weight=\"bold\" \
+         text-anchor=\"middle\">{}</text>\n",
+        width / 2,
+        PADDING + 26,
+        escape(title)
+    );
+
+    for (i, game) in games.iter().enumerate() {
+        let left = PADDING + (i % BOARDS_PER_LINE) * (board_width + BOARD_GAP);
+        let top = PADDING + TITLE_HEIGHT + (i / BOARDS_PER_LINE) * (board_height + BOARD_GAP);
+
+        for (row, guess) in game.guesses().iter().enumerate() {
+            let y = top + row * (TILE + GAP);
+            for (column, (letter, state)) in guess.letters().enumerate() {
+                let x = left + column * (TILE + GAP);
+                let _ = writeln!(
+                    svg,
+                    "<rect x=\"{x}\" y=\"{y}\" width=\"{TILE}\" height=\"{TILE}\" fill=\"{}\"/>",
+                    fill(state)
+                );
+                // a row given up in blitz has nothing to show
+                if letters && !guess.is_forfeited() {
+                    let _ = writeln!(
+                        svg,
+                        "<text x=\"{}\" y=\"{}\" fill=\"{TEXT}\" font-size=\"32\" \
+                         font-weight=\"bold\" text-anchor=\"middle\">{}</text>",
+                        x + TILE / 2,
+                        y + TILE / 2 + 11,
+                        escape(&letter.to_uppercase().to_string())
+                    );
+                }
+            }
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
@@ -0,0 +1,203 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Stylize},
+    text::{Line, Span},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{
+    board::{self, Board},
+    config::Colors,
+};
+
+// the keyboard being typed on, so the one on screen has the letters in the same places. what's
+// typed already comes through as the right letters, it's only drawing and clicking that change
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Layout {
+    #[default]
+    Qwerty,
+    Azerty,
+    Dvorak,
+    Colemak,
+}
+
+impl Layout {
+    const ALL: [Self; 4] = [Self::Qwerty, Self::Azerty, Self::Dvorak, Self::Colemak];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Qwerty => "qwerty",
+            Self::Azerty => "azerty",
+            Self::Dvorak => "dvorak",
+            Self::Colemak => "colemak",
+        }
+    }
+
+    // just the letters, punctuation on the same rows is left out
+    const fn rows(self) -> [&'static str; ROWS] {
+        match self {
+            Self::Qwerty => ["QWERTYUIOP", "ASDFGHJKL", "ZXCVBNM"],
+            Self::Azerty => ["AZERTYUIOP", "QSDFGHJKLM", "WXCVBN"],
+            Self::Dvorak => ["PYFGCRL", "AOEUIDHTNS", "QJKXBMWVZ"],
+            Self::Colemak => ["QWFPGJLUY", "ARSTDHNEIO", "ZXCVBKM"],
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let i = Self::ALL
+            .iter()
+            .position(|&layout| layout == self)
+            .unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+}
+
+const ROWS: usize = 3;
+// the most letters any layout has on a row
+const WIDEST_ROW: usize = 10;
+#[allow(clippy::cast_possible_truncation)]
+pub const HEIGHT: u16 = ROWS as u16;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Key {
+    Letter(char),
+    Enter,
+    Backspace,
+}
+
+impl Key {
+    fn label(self) -> String {
+        match self {
+            Self::Letter(letter) => format!(" {letter} "),
+            Self::Enter => String::from(" ENTER "),
+            Self::Backspace => String::from(" ⌫ "),
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn width(self) -> u16 {
+        self.label().chars().count() as u16
+    }
+}
+
+// like the official game, enter and backspace sit either side of the bottom row
+fn keys(layout: Layout, row: usize) -> Vec<Key> {
+    let mut keys = layout.rows()[row]
+        .chars()
+        .map(Key::Letter)
+        .collect::<Vec<Key>>();
+    if row == ROWS - 1 {
+        keys.insert(0, Key::Enter);
+        keys.push(Key::Backspace);
+    }
+
+    keys
+}
+
+// every letter tinted by what the board has given away about it, for when there's only room
+// for a few lines under each board
+pub fn compact<'a>(board: &Board, colors: &Colors, layout: Layout) -> Vec<Line<'a>> {
+    layout
+        .rows()
+        .iter()
+        .map(|row| {
+            let spans = row
+                .chars()
+                .map(|letter| colors.letter(letter, board.letter_state(letter)))
+                .collect::<Vec<Span>>();
+
+            Line::from(spans).centered()
+        })
+        .collect()
+}
+
+// the keyboard that can be clicked, drawn as centered lines that `key_at` can find keys in.
+// with `repeats` letters the answer has more than one of say how many, where there's room
+pub fn full<'a>(board: &Board, colors: &Colors, repeats: bool, layout: Layout) -> Vec<Line<'a>> {
+    (0..ROWS)
+        .map(|row| {
+            let spans = keys(layout, row)
+                .into_iter()
+                .map(|key| {
+                    let span = match key {
+                        // symbols already take up the padding around the letter
+                        Key::Letter(letter) if colors.symbols => {
+                            colors.letter(letter, board.letter_state(letter))
+                        }
+                        Key::Letter(letter) => {
+                            let count = board.letter_count(letter, repeats);
+                            let label = if repeats && count > 1 {
+                                format!(" {letter}{}", board::superscript(count))
+                            } else {
+                                key.label()
+                            };
+                            let letter = colors.letter(letter, board.letter_state(letter));
+                            Span::from(label).style(letter.style)
+                        }
+                        Key::Enter | Key::Backspace => Span::from(key.label()),
+                    };
+                    span.bold()
+                })
+                .collect::<Vec<Span>>();
+
+            Line::from(spans).centered()
+        })
+        .collect()
+}
+
+// which key of a `full` keyboard drawn in `area` is at the given cell
+pub fn key_at(area: Rect, layout: Layout, column: u16, row: u16) -> Option<Key> {
+    if row < area.y || row >= area.y + HEIGHT.min(area.height) {
+        return None;
+    }
+
+    let keys = keys(layout, usize::from(row - area.y));
+    let width = keys.iter().map(|key| key.width()).sum::<u16>();
+    let mut x = area.x + area.width.saturating_sub(width) / 2;
+
+    for key in keys {
+        if (x..x + key.width()).contains(&column) {
+            return Some(key);
+        }
+        x += key.width();
+    }
+
+    None
+}
+
+// how wide every `heatmap` line is, so two fit side by side. the rows below are indented by one
+// more each
+pub const HEATMAP_WIDTH: usize = WIDEST_ROW * 2 + ROWS - 1;
+
+// every letter shaded by how often it shows up in `counts`, the most common ones filled in.
+// rows are staggered like a real keyboard and padded to the same width
+pub fn heatmap<'a>(counts: &HashMap<char, usize>, color: Color, layout: Layout) -> Vec<Line<'a>> {
+    let most = counts.values().copied().max().unwrap_or_default().max(1);
+
+    layout
+        .rows()
+        .iter()
+        .enumerate()
+        .map(|(indent, row)| {
+            let mut spans = vec![Span::from(" ".repeat(indent))];
+            for letter in row.chars() {
+                let count = counts.get(&letter).copied().unwrap_or_default();
+                let span = Span::from(letter.to_string()).fg(color);
+                spans.push(match count * 3 / most {
+                    _ if count == 0 => Span::from(letter.to_string()).dark_gray(),
+                    0 => span.dim(),
+                    1 => span,
+                    _ => span.bold().reversed(),
+                });
+                spans.push(Span::from(" "));
+            }
+            spans.push(Span::from(
+                " ".repeat(HEATMAP_WIDTH.saturating_sub(indent + row.len() * 2)),
+            ));
+
+            Line::from(spans)
+        })
+        .collect()
+}
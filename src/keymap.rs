@@ -0,0 +1,439 @@
+use anyhow::{bail, Context, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+// everything a key can be bound to, letters are always typed as themselves
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Action {
+    Submit,
+    Backspace,
+    ClearInput,
+    Quit,
+    Close,
+    ToggleHardMode,
+    Copy,
+    NewRound,
+    Help,
+    Stats,
+    Analysis,
+    History,
+    Achievements,
+    Leaderboard,
+    Settings,
+    Assist,
+    LetterFrequencies,
+    Hint,
+    Complete,
+    ScrollUp,
+    ScrollDown,
+    Top,
+    Bottom,
+    Insert,
+    Normal,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct KeyBindings {
+    pub submit: Keys,
+    pub backspace: Keys,
+    pub clear_input: Keys,
+    pub quit: Keys,
+    pub close: Keys,
+    pub toggle_hard_mode: Keys,
+    pub copy: Keys,
+    pub new_round: Keys,
+    pub help: Keys,
+    pub stats: Keys,
+    pub analysis: Keys,
+    pub history: Keys,
+    pub achievements: Keys,
+    pub leaderboard: Keys,
+    pub settings: Keys,
+    pub assist: Keys,
+    pub letter_frequencies: Keys,
+    pub hint: Keys,
+    pub complete: Keys,
+    pub scroll_up: Keys,
+    pub scroll_down: Keys,
+    pub top: Keys,
+    pub bottom: Keys,
+    // with `modal` on, letters are only typed after `insert` and `normal` stops typing them
+    // again, so the other keys work mid-game
+    pub modal: bool,
+    pub insert: Keys,
+    pub normal: Keys,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            submit: Keys::new(KeyCode::Enter, KeyModifiers::NONE),
+            backspace: Keys::new(KeyCode::Backspace, KeyModifiers::NONE),
+            clear_input: Keys::new(KeyCode::Char('w'), KeyModifiers::CONTROL),
+            quit: Keys::new(KeyCode::Char('c'), KeyModifiers::CONTROL),
+            close: Keys(vec![
+                KeyBinding::new(KeyCode::Char('q'), KeyModifiers::NONE),
+                KeyBinding::new(KeyCode::Esc, KeyModifiers::NONE),
+                KeyBinding::new(KeyCode::Enter, KeyModifiers::NONE),
+            ]),
+            toggle_hard_mode: Keys::new(KeyCode::Char('d'), KeyModifiers::CONTROL),
+            copy: Keys::new(KeyCode::Char('c'), KeyModifiers::NONE),
+            new_round: Keys::new(KeyCode::Char('n'), KeyModifiers::NONE),
+            help: Keys::new(KeyCode::Char('?'), KeyModifiers::NONE),
+            stats: Keys::new(KeyCode::Char('s'), KeyModifiers::NONE),
+            analysis: Keys::new(KeyCode::Char('a'), KeyModifiers::NONE),
+            history: Keys::new(KeyCode::Char('H'), KeyModifiers::NONE),
+            achievements: Keys::new(KeyCode::Char('t'), KeyModifiers::NONE),
+            leaderboard: Keys::new(KeyCode::Char('l'), KeyModifiers::NONE),
+            settings: Keys::new(KeyCode::Char('o'), KeyModifiers::NONE),
+            assist: Keys::new(KeyCode::Tab, KeyModifiers::NONE),
+            letter_frequencies: Keys::new(KeyCode::Char('f'), KeyModifiers::CONTROL),
+            hint: Keys::new(KeyCode::F(1), KeyModifiers::NONE),
+            complete: Keys(vec![
+                KeyBinding::new(KeyCode::Right, KeyModifiers::NONE),
+                KeyBinding::new(KeyCode::Tab, KeyModifiers::NONE),
+            ]),
+            scroll_up: Keys(vec![
+                KeyBinding::new(KeyCode::Up, KeyModifiers::NONE),
+                KeyBinding::new(KeyCode::Char('k'), KeyModifiers::NONE),
+            ]),
+            scroll_down: Keys(vec![
+                KeyBinding::new(KeyCode::Down, KeyModifiers::NONE),
+                KeyBinding::new(KeyCode::Char('j'), KeyModifiers::NONE),
+            ]),
+            top: Keys(vec![
+                KeyBinding::new(KeyCode::Home, KeyModifiers::NONE),
+                KeyBinding::new(KeyCode::Char('g'), KeyModifiers::NONE)
+                    .after(KeyCode::Char('g'), KeyModifiers::NONE),
+            ]),
+            bottom: Keys(vec![
+                KeyBinding::new(KeyCode::End, KeyModifiers::NONE),
+                KeyBinding::new(KeyCode::Char('G'), KeyModifiers::NONE),
+            ]),
+            modal: false,
+            insert: Keys::new(KeyCode::Char('i'), KeyModifiers::NONE),
+            normal: Keys::new(KeyCode::Esc, KeyModifiers::NONE),
+        }
+    }
+}
+
+impl KeyBindings {
+    pub const fn keys(&self, action: Action) -> &Keys {
+        match action {
+            Action::Submit => &self.submit,
+            Action::Backspace => &self.backspace,
+            Action::ClearInput => &self.clear_input,
+            Action::Quit => &self.quit,
+            Action::Close => &self.close,
+            Action::ToggleHardMode => &self.toggle_hard_mode,
+            Action::Copy => &self.copy,
+            Action::NewRound => &self.new_round,
+            Action::Help => &self.help,
+            Action::Stats => &self.stats,
+            Action::Analysis => &self.analysis,
+            Action::History => &self.history,
+            Action::Achievements => &self.achievements,
+            Action::Leaderboard => &self.leaderboard,
+            Action::Settings => &self.settings,
+            Action::Assist => &self.assist,
+            Action::LetterFrequencies => &self.letter_frequencies,
+            Action::Hint => &self.hint,
+            Action::Complete => &self.complete,
+            Action::ScrollUp => &self.scroll_up,
+            Action::ScrollDown => &self.scroll_down,
+            Action::Top => &self.top,
+            Action::Bottom => &self.bottom,
+            Action::Insert => &self.insert,
+            Action::Normal => &self.normal,
+        }
+    }
+
+    // only the actions that make sense on the current screen are looked at, in order, so the
+    // same key can mean different things in different places (e.g. esc closing a popup
+    // before it quits)
+    pub fn action(&self, key_event: KeyEvent, actions: &[Action]) -> Option<Action> {
+        actions
+            .iter()
+            .copied()
+            .find(|&action| self.keys(action).matches(key_event))
+    }
+}
+
+// the first key of a two-key binding like "g g", held until the next one says what it was
+#[derive(Debug, Default)]
+pub struct Pending(Option<KeyEvent>);
+
+impl Pending {
+    // same as `KeyBindings::action`, except a key that starts one of the actions' two-key
+    // bindings does nothing yet. if the next key doesn't finish it, that key counts on its own
+    pub fn action(
+        &mut self,
+        keys: &KeyBindings,
+        key_event: KeyEvent,
+        actions: &[Action],
+    ) -> Option<Action> {
+        if let Some(previous) = self.0.take() {
+            let finished = actions
+                .iter()
+                .copied()
+                .find(|&action| keys.keys(action).matches_after(previous, key_event));
+            if finished.is_some() {
+                return finished;
+            }
+        }
+
+        if actions
+            .iter()
+            .any(|&action| keys.keys(action).starts(key_event))
+        {
+            self.0 = Some(key_event);
+            return None;
+        }
+
+        keys.action(key_event, actions)
+    }
+}
+
+// one or more keys for the same action, written as "enter" or ["enter", "ctrl+m"], or two
+// pressed one after the other like "g g"
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(from = "KeysRepr", into = "KeysRepr")]
+pub struct Keys(Vec<KeyBinding>);
+
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum KeysRepr {
+    One(KeyBinding),
+    Many(Vec<KeyBinding>),
+}
+
+impl Keys {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self(vec![KeyBinding::new(code, modifiers)])
+    }
+
+    pub fn matches(&self, key_event: KeyEvent) -> bool {
+        self.0.iter().any(|binding| binding.matches(key_event))
+    }
+
+    fn matches_after(&self, previous: KeyEvent, key_event: KeyEvent) -> bool {
+        self.0
+            .iter()
+            .any(|binding| binding.matches_after(previous, key_event))
+    }
+
+    fn starts(&self, key_event: KeyEvent) -> bool {
+        self.0.iter().any(|binding| {
+            binding
+                .first
+                .is_some_and(|(code, modifiers)| pressed(code, modifiers, key_event))
+        })
+    }
+
+    // just the first binding, for hints where the whole list would be too long
+    pub fn primary(&self) -> String {
+        self.0
+            .first()
+            .map_or_else(|| String::from("unbound"), ToString::to_string)
+    }
+}
+
+impl From<KeysRepr> for Keys {
+    fn from(repr: KeysRepr) -> Self {
+        Self(match repr {
+            KeysRepr::One(binding) => vec![binding],
+            KeysRepr::Many(bindings) => bindings,
+        })
+    }
+}
+
+impl From<Keys> for KeysRepr {
+    fn from(keys: Keys) -> Self {
+        match <[KeyBinding; 1]>::try_from(keys.0) {
+            Ok([binding]) => Self::One(binding),
+            Err(bindings) => Self::Many(bindings),
+        }
+    }
+}
+
+impl Display for Keys {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "unbound");
+        }
+
+        for (i, binding) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "/")?;
+            }
+            write!(f, "{binding}")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn pressed(code: KeyCode, modifiers: KeyModifiers, key_event: KeyEvent) -> bool {
+    // shift is implied by an uppercase character, don't require it twice
+    let pressed_modifiers = match key_event.code {
+        KeyCode::Char(_) => key_event.modifiers - KeyModifiers::SHIFT,
+        _ => key_event.modifiers,
+    };
+
+    key_event.code == code && pressed_modifiers == modifiers
+}
+
+// written as e.g. "enter", "ctrl+c", "f1" or "g g" in the config file
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct KeyBinding {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+    // pressed just before, for two-key bindings
+    first: Option<(KeyCode, KeyModifiers)>,
+}
+
+impl KeyBinding {
+    pub const fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self {
+            code,
+            modifiers,
+            first: None,
+        }
+    }
+
+    pub const fn after(self, code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self {
+            first: Some((code, modifiers)),
+            ..self
+        }
+    }
+
+    // two-key bindings only go through `Pending`
+    pub fn matches(self, key_event: KeyEvent) -> bool {
+        self.first.is_none() && pressed(self.code, self.modifiers, key_event)
+    }
+
+    fn matches_after(self, previous: KeyEvent, key_event: KeyEvent) -> bool {
+        self.first
+            .is_some_and(|(code, modifiers)| pressed(code, modifiers, previous))
+            && pressed(self.code, self.modifiers, key_event)
+    }
+
+    fn parse_key(s: &str) -> Result<Self> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut parts = s.split('+').collect::<Vec<&str>>();
+        let Some(key) = parts.pop() else {
+            bail!("empty key binding");
+        };
+
+        for modifier in parts {
+            modifiers |= match modifier.to_lowercase().as_str() {
+                "ctrl" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                other => bail!("unknown modifier {other} in key binding {s}"),
+            };
+        }
+
+        let code = match key.to_lowercase().as_str() {
+            "enter" => KeyCode::Enter,
+            "backspace" => KeyCode::Backspace,
+            "esc" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "space" => KeyCode::Char(' '),
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            f if f.len() > 1 && f.starts_with('f') => KeyCode::F(
+                f[1..]
+                    .parse()
+                    .with_context(|| format!("unknown key {key} in key binding {s}"))?,
+            ),
+            _ => {
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => bail!("unknown key {key} in key binding {s}"),
+                }
+            }
+        };
+
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+impl FromStr for KeyBinding {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().split_once(' ') {
+            Some((first, second)) => {
+                let first = Self::parse_key(first)?;
+                Ok(Self::parse_key(second.trim())?.after(first.code, first.modifiers))
+            }
+            None => Self::parse_key(s),
+        }
+    }
+}
+
+impl TryFrom<String> for KeyBinding {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> Result<Self> {
+        s.parse()
+    }
+}
+
+impl From<KeyBinding> for String {
+    fn from(binding: KeyBinding) -> Self {
+        binding.to_string()
+    }
+}
+
+impl Display for KeyBinding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some((code, modifiers)) = self.first {
+            write_key(f, code, modifiers)?;
+            write!(f, " ")?;
+        }
+        write_key(f, self.code, self.modifiers)
+    }
+}
+
+fn write_key(f: &mut Formatter<'_>, code: KeyCode, modifiers: KeyModifiers) -> fmt::Result {
+    for (modifier, name) in [
+        (KeyModifiers::CONTROL, "ctrl"),
+        (KeyModifiers::ALT, "alt"),
+        (KeyModifiers::SHIFT, "shift"),
+    ] {
+        if modifiers.contains(modifier) {
+            write!(f, "{name}+")?;
+        }
+    }
+
+    match code {
+        KeyCode::Enter => write!(f, "enter"),
+        KeyCode::Backspace => write!(f, "backspace"),
+        KeyCode::Esc => write!(f, "esc"),
+        KeyCode::Tab => write!(f, "tab"),
+        KeyCode::Char(' ') => write!(f, "space"),
+        KeyCode::Up => write!(f, "up"),
+        KeyCode::Down => write!(f, "down"),
+        KeyCode::Left => write!(f, "left"),
+        KeyCode::Right => write!(f, "right"),
+        KeyCode::Home => write!(f, "home"),
+        KeyCode::End => write!(f, "end"),
+        KeyCode::F(n) => write!(f, "f{n}"),
+        KeyCode::Char(c) => write!(f, "{c}"),
+        other => write!(f, "{other:?}"),
+    }
+}
@@ -0,0 +1,498 @@
+use anyhow::{bail, ensure, Context, Result};
+use chrono::{Days, NaiveDate};
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{Paragraph, Wrap},
+    Frame,
+};
+use serde::Deserialize;
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+    sync::mpsc::{self, Receiver, TryRecvError},
+    thread,
+    time::Duration,
+};
+use tracing::{debug, debug_span, warn};
+use ureq::Agent;
+
+use wordle_tui::wordle_core::WORD_LENGTH;
+
+use crate::{
+    answers::{self, AnswerPool},
+    challenge,
+    clock::Timezone,
+    event_loop::{Crossterm, Event, Events},
+    extra_words::ExtraWords,
+    network::{self, Validators},
+    offline, practice_word, storage,
+    tui::Terminal,
+    word_list::{self, Cache, WordList, WordListSource, Words},
+    GameMode,
+};
+
+// the longest wait between retries, however many attempts are allowed
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+// how long before midnight tomorrow's puzzle is fetched, when prefetching is on
+const PREFETCH_WINDOW: Duration = Duration::from_hours(3);
+const SPINNER: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+// what `load` gets from the network, with an agent that's the wordle api and wherever the
+// word list is. the caching, retrying and picking the puzzle around it don't change, so a
+// stand-in for these can play a whole game without going online
+pub trait Source: Clone + Send + 'static {
+    // the wordle api's json for a day's puzzle
+    fn daily(&self, date: NaiveDate) -> Result<String>;
+    // see `WordListSource::fetch`
+    fn word_list(&self, list: &WordListSource, cached: Option<&Validators>) -> Result<WordList>;
+}
+
+impl Source for Agent {
+    fn daily(&self, date: NaiveDate) -> Result<String> {
+        network::get_string(
+            self,
+            &format!(
+                "https://www.nytimes.com/svc/wordle/v2/{}.json",
+                date.format("%Y-%m-%d")
+            ),
+        )
+        .context("failed to fetch wordle api")
+    }
+
+    fn word_list(&self, list: &WordListSource, cached: Option<&Validators>) -> Result<WordList> {
+        list.fetch(self, cached)
+    }
+}
+
+#[derive(Debug)]
+pub struct Puzzle {
+    pub solution: String,
+    // only known for the nyt's daily puzzle
+    pub meta: Option<PuzzleMeta>,
+    pub word_list: HashSet<String>,
+    // the words the list says its answers come from, empty if it doesn't
+    pub answers: HashSet<String>,
+}
+
+impl Puzzle {
+    pub fn offline(request: &LoadRequest) -> Result<Self> {
+        let words = word_list::with_length(
+            request.word_list.offline().fetch_words(&request.agent)?,
+            request.word_length,
+        )?;
+        let solution = local_solution(request, &words)?;
+
+        let mut word_list = words.accepted;
+        ExtraWords::load(&request.word_list_extensions).apply(
+            &mut word_list,
+            &solution,
+            request.word_length,
+        );
+        Ok(Self {
+            solution,
+            meta: None,
+            word_list,
+            answers: words.answers,
+        })
+    }
+}
+
+// puzzles that don't come from the nyt pick their word from the list itself
+fn local_solution(request: &LoadRequest, words: &Words) -> Result<String> {
+    let word_list = &words.accepted;
+    let pool = || answers::pool(word_list, &words.answers, request.answer_pool);
+    match request.mode {
+        GameMode::Daily | GameMode::Archive => {
+            offline::solution_for(request.date, &pool()).context("word list is empty")
+        }
+        // absurdle only starts from a random word, it moves as soon as there's a guess
+        GameMode::Practice | GameMode::Absurdle => {
+            practice_word(&pool(), request.seed, 0).context("word list is empty")
+        }
+        // checked here rather than when the challenge was made, the word lists might differ
+        GameMode::Challenge => {
+            let word = request
+                .challenge
+                .clone()
+                .context("no challenge word given")?;
+            ensure!(
+                word_list.contains(&word),
+                "{word} isn't in the selected word list"
+            );
+            Ok(word)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PuzzleMeta {
+    // the nyt's internal id, not the number players know the puzzle by
+    pub id: u64,
+    #[serde(rename = "days_since_launch")]
+    pub number: u64,
+    pub editor: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct DailyResponse {
+    solution: String,
+    #[serde(flatten)]
+    meta: Option<PuzzleMeta>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LoadRequest {
+    pub mode: GameMode,
+    pub date: NaiveDate,
+    // where `date` came from, for working out when tomorrow starts
+    pub timezone: Timezone,
+    pub agent: Agent,
+    pub word_list: WordListSource,
+    pub word_list_cache: PathBuf,
+    pub word_list_max_age: Duration,
+    // words added to or removed from the list's guesses
+    pub word_list_extensions: PathBuf,
+    // the word to play in a challenge, already decoded
+    pub challenge: Option<String>,
+    // picks the practice word instead of it being random
+    pub seed: Option<u64>,
+    // only words this long are played or accepted
+    pub word_length: usize,
+    pub answer_pool: AnswerPool,
+    // how many times each request is tried
+    pub attempts: u32,
+    pub daily_cache: PathBuf,
+    // fetch tomorrow's daily puzzle in the background if it's late enough
+    pub prefetch: bool,
+    // fetch the word list again even if the cache is still fresh
+    pub refresh_word_list: bool,
+    // how often the loading screen redraws
+    pub tick_rate: Duration,
+}
+
+enum LoadEvent {
+    Progress(String),
+    Loaded(Puzzle),
+    Failed(String),
+}
+
+enum State {
+    Loading(String),
+    Failed(String),
+}
+
+// fetches the puzzle on a background thread while showing a spinner, returns none if the
+// player gave up instead of retrying or switching to offline play
+pub fn run(terminal: &mut Terminal, request: &LoadRequest) -> Result<Option<Puzzle>> {
+    let mut loader = spawn(request.clone());
+    let mut events = Crossterm::new(request.tick_rate);
+    let mut state = State::Loading(String::from("starting..."));
+    let mut frame_count = 0_usize;
+
+    loop {
+        terminal.draw(|frame| draw(frame, &state, frame_count))?;
+        frame_count = frame_count.wrapping_add(1);
+
+        if let State::Loading(_) = state {
+            loop {
+                match loader.try_recv() {
+                    Ok(LoadEvent::Progress(message)) => state = State::Loading(message),
+                    Ok(LoadEvent::Loaded(puzzle)) => return Ok(Some(puzzle)),
+                    // the loader's gone after this, which isn't it stopping unexpectedly
+                    Ok(LoadEvent::Failed(message)) => {
+                        state = State::Failed(message);
+                        break;
+                    }
+                    Err(TryRecvError::Empty) => break,
+                    // the loader panicked rather than sending a result
+                    Err(TryRecvError::Disconnected) => {
+                        state = State::Failed(String::from("loading stopped unexpectedly"));
+                        break;
+                    }
+                }
+            }
+        }
+
+        let Event::Key(key_event) = events.next()? else {
+            continue;
+        };
+
+        match (&state, key_event.code) {
+            (_, KeyCode::Char('q') | KeyCode::Esc) => return Ok(None),
+            (_, KeyCode::Char('c')) if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                return Ok(None)
+            }
+            (State::Failed(_), KeyCode::Char('r')) => {
+                loader = spawn(request.clone());
+                state = State::Loading(String::from("retrying..."));
+            }
+            // stay on this screen if that's broken too, it can still be retried
+            (State::Failed(_), KeyCode::Char('o')) => match Puzzle::offline(request) {
+                Ok(puzzle) => return Ok(Some(puzzle)),
+                Err(err) => state = State::Failed(format!("{err:#}")),
+            },
+            _ => {}
+        }
+    }
+}
+
+fn draw(frame: &mut Frame, state: &State, frame_count: usize) {
+    let [_, message_area, hint_area, _] = Layout::vertical([
+        Constraint::Percentage(40),
+        Constraint::Length(3),
+        Constraint::Length(1),
+        Constraint::Min(0),
+    ])
+    .areas(frame.area());
+
+    let (message, hint) = match state {
+        State::Loading(message) => (
+            Line::from(format!(
+                "{} {message}",
+                SPINNER[frame_count % SPINNER.len()]
+            )),
+            "q to quit",
+        ),
+        State::Failed(message) => (
+            Line::from(message.as_str()).style(Style::default().fg(Color::LightRed)),
+            "r to retry, o to play offline, q to quit",
+        ),
+    };
+
+    frame.render_widget(
+        Paragraph::new(message).centered().wrap(Wrap { trim: true }),
+        message_area,
+    );
+    frame.render_widget(
+        Paragraph::new(hint)
+            .style(Style::default().fg(Color::DarkGray).dim())
+            .centered(),
+        hint_area,
+    );
+}
+
+fn spawn(request: LoadRequest) -> Receiver<LoadEvent> {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let report = |message: &str| {
+            let _ = sender.send(LoadEvent::Progress(message.to_owned()));
+        };
+        let event = match load(&request, &request.agent, &report) {
+            Ok(puzzle) => LoadEvent::Loaded(puzzle),
+            Err(err) => LoadEvent::Failed(format!("{err:#}")),
+        };
+
+        // the receiver is only gone if the player already quit
+        let _ = sender.send(event);
+    });
+
+    receiver
+}
+
+// `report` hears about each step as it starts, and about retries. everything that would go
+// over the network goes to `source` instead, normally `request.agent`
+pub fn load(request: &LoadRequest, source: &impl Source, report: &impl Fn(&str)) -> Result<Puzzle> {
+    let _load = debug_span!("load", mode = ?request.mode, list = ?request.word_list).entered();
+    // today's nyt answer only makes sense with the nyt word list, and at its length
+    let daily = match (request.mode, &request.word_list) {
+        (GameMode::Daily, WordListSource::Nyt) if request.word_length == WORD_LENGTH => {
+            report("fetching today's puzzle...");
+            let daily = with_retries(report, request.attempts, || {
+                cached_daily(source, &request.daily_cache, request.date)
+            })?;
+            if request.prefetch {
+                prefetch_tomorrow(request, source);
+            }
+            Some(daily)
+        }
+        _ => None,
+    };
+
+    report("loading word list...");
+    let words = word_list::with_length(
+        load_word_list(request, source, report)?,
+        request.word_length,
+    )?;
+
+    let (solution, meta) = match daily {
+        Some(daily) => (daily.solution, daily.meta),
+        None => (local_solution(request, &words)?, None),
+    };
+    debug!(
+        %solution,
+        accepted = words.accepted.len(),
+        answers = words.answers.len(),
+        "puzzle picked"
+    );
+    ensure!(
+        words.accepted.contains(&solution.to_uppercase()),
+        "today's word isn't in the selected word list"
+    );
+
+    // only after the solution's picked, so added words are never the answer
+    let mut word_list = words.accepted;
+    ExtraWords::load(&request.word_list_extensions).apply(
+        &mut word_list,
+        &solution,
+        request.word_length,
+    );
+    Ok(Puzzle {
+        solution,
+        meta,
+        word_list,
+        answers: words.answers,
+    })
+}
+
+// network hiccups are common enough that one failure shouldn't end the game
+fn with_retries<T>(
+    report: &impl Fn(&str),
+    attempts: u32,
+    mut f: impl FnMut() -> Result<T>,
+) -> Result<T> {
+    let mut attempt = 1;
+    loop {
+        match f() {
+            Ok(value) => return Ok(value),
+            // what a retry here would just get again
+            Err(err) if attempt >= attempts || !network::is_transient(&err) => return Err(err),
+            Err(_) => {
+                let delay = Duration::from_secs(1_u64.checked_shl(attempt - 1).unwrap_or(u64::MAX))
+                    .min(MAX_RETRY_DELAY);
+                report(&format!(
+                    "request failed, retrying in {}s (attempt {}/{attempts})...",
+                    delay.as_secs(),
+                    attempt + 1
+                ));
+                thread::sleep(delay);
+                attempt += 1;
+            }
+        }
+    }
+}
+
+fn load_word_list(
+    request: &LoadRequest,
+    source: &impl Source,
+    report: &impl Fn(&str),
+) -> Result<Words> {
+    let _cache = debug_span!("word_list_cache").entered();
+    let list = &request.word_list;
+    let Some(cache_path) = list.cache_path(&request.word_list_cache) else {
+        return match source.word_list(list, None)? {
+            WordList::Changed(words, _) => Ok(words),
+            WordList::Unchanged => bail!("the word list server had nothing to send"),
+        };
+    };
+
+    let cached = Cache::load(&cache_path);
+    if let Some(cached) = cached.as_ref().filter(|cached| {
+        !request.refresh_word_list && cached.is_fresh(list, request.word_list_max_age)
+    }) {
+        debug!(words = cached.words.accepted.len(), "hit");
+        return Ok(cached.words.clone());
+    }
+    debug!("missing, stale or refreshed on purpose");
+
+    report("fetching word list...");
+
+    // a forced refresh downloads it again whatever the server says
+    let validators = cached
+        .as_ref()
+        .filter(|_| !request.refresh_word_list)
+        .and_then(|cached| cached.validators(list))
+        .cloned();
+    match (
+        with_retries(report, request.attempts, || {
+            source.word_list(list, validators.as_ref())
+        }),
+        cached,
+    ) {
+        (Ok(WordList::Changed(fetched_wl, validators)), _) => {
+            debug!(words = fetched_wl.accepted.len(), "downloaded");
+            Cache::save(&cache_path, list, &fetched_wl, validators.as_ref())?;
+            Ok(fetched_wl)
+        }
+        // still the same, it's just fresh again
+        (Ok(WordList::Unchanged), Some(cached)) => {
+            debug!("unchanged on the server");
+            Cache::save(&cache_path, list, &cached.words, validators.as_ref())?;
+            Ok(cached.words)
+        }
+        (Ok(WordList::Unchanged), None) => bail!("there's no cached word list to reuse"),
+        // an outdated list is still better than none
+        (Err(err), Some(cached)) => {
+            warn!("using the outdated cached word list: {err:#}");
+            Ok(cached.words)
+        }
+        // the bundled list is close enough to the nyt one that it's not worth stopping over
+        (Err(err), None) if *list == WordListSource::Nyt => {
+            warn!("using the bundled word list: {err:#}");
+            Ok(Words::accepted(offline::word_list()))
+        }
+        (Err(err), None) => Err(err),
+    }
+}
+
+// a day's puzzle never changes, so it's only fetched once. kept obscured like everything else
+// that has the answer in it, so it can't be read off the disk at a glance
+fn cached_daily(source: &impl Source, cache: &Path, date: NaiveDate) -> Result<DailyResponse> {
+    let _cache = debug_span!("daily_cache", %date).entered();
+    let path = cache.join(format!("{}.json", date.format("%Y-%m-%d")));
+    let cached = fs::read_to_string(&path)
+        .ok()
+        .and_then(|contents| challenge::unobscure(contents.trim()))
+        .and_then(|json| serde_json::from_str(&json).ok());
+    if let Some(daily) = cached {
+        debug!("hit");
+        return Ok(daily);
+    }
+
+    let json = source.daily(date)?;
+    let daily = serde_json::from_str(&json).context("unexpected response from the wordle api")?;
+    // the puzzle's already here, not being able to cache it shouldn't stop the game
+    let _ = storage::write(&path, challenge::obscure(&json));
+    prune_daily_cache(cache, date);
+
+    Ok(daily)
+}
+
+// yesterday's is kept for anyone still playing it past midnight
+fn prune_daily_cache(cache: &Path, today: NaiveDate) {
+    let Ok(entries) = fs::read_dir(cache) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let date = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| NaiveDate::parse_from_str(stem, "%Y-%m-%d").ok());
+        if date.is_some_and(|date| date < today - Days::new(1)) {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+// tomorrow's puzzle is out well before midnight, fetching it in the last few hours of the day
+// means the next launch doesn't wait on the network, or need it
+fn prefetch_tomorrow(request: &LoadRequest, source: &impl Source) {
+    let Some(tomorrow) = request.date.succ_opt() else {
+        return;
+    };
+    if request.timezone.until_midnight() > PREFETCH_WINDOW {
+        return;
+    }
+
+    let (source, cache) = (source.clone(), request.daily_cache.clone());
+    // nobody waits on this, if the game's closed first it's just fetched next time
+    thread::spawn(move || {
+        let _ = cached_daily(&source, &cache, tomorrow);
+    });
+}
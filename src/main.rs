@@ -1,343 +1,2173 @@
-use anyhow::{bail, Context, Result};
-use chrono::Local;
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use anyhow::{ensure, Result};
+use chrono::NaiveDate;
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
-    layout::{Constraint, Layout},
+    backend::Backend,
+    layout::{Constraint, Flex, Layout, Rect},
     style::{Color, Style, Stylize},
     text::{Line, Span},
-    widgets::{List, ListItem, Paragraph},
-    DefaultTerminal, Frame,
+    widgets::{Block, Paragraph, Wrap},
+    Frame,
 };
-use std::{
-    collections::{HashMap, HashSet},
-    fs, mem,
-};
-use std::hash::{Hash, Hasher};
-use ureq::serde_json::{self, Value};
+use std::{cell::Cell, collections::HashSet, iter, path::PathBuf, time::Duration};
+use tracing::{debug, debug_span, info, warn};
+use ureq::Agent;
+use wordle_tui::wordle_core::{self, Game, GameStatus, GuessError, MAX_GUESSES};
 
-fn main() -> Result<()> {
-    let wordle_api_response = ureq::get(&format!(
-        "https://www.nytimes.com/svc/wordle/v2/{}.json",
-        Local::now().format("%Y-%m-%d")
-    ))
-        .call()
-        .context("failed to fetch wordle api")?
-        .into_json::<Value>()?;
-
-    let Value::String(solution) = &wordle_api_response["solution"] else {
-        bail!("solution value was not type of string");
-    };
+// the smallest terminal the full keyboard and a compact board fit in
+const MIN_WIDTH: u16 = 32;
+const MIN_HEIGHT: u16 = 16;
+// there's no getting lucky against absurdle, so it gets a couple more rows to work it out
+const ABSURDLE_GUESSES: usize = MAX_GUESSES + 2;
 
-    let word_list = if let Ok(word_list_cache) = fs::read_to_string(".word-list.cache.txt") {
-        word_list_cache
-            .lines()
-            .map(ToString::to_string)
-            .collect::<HashSet<String>>()
-    } else {
-        println!("fetching word list...");
+mod achievements;
+mod analysis;
+mod animation;
+mod answers;
+mod assist;
+mod autocomplete;
+mod bench;
+mod board;
+mod challenge;
+mod cli;
+mod clipboard;
+mod clock;
+mod clock_skew;
+mod color_support;
+mod config;
+mod countdown;
+mod definition;
+mod duel;
+#[cfg(test)]
+mod end_to_end;
+mod event_loop;
+mod export;
+mod extra_words;
+mod headless;
+mod help;
+mod hints;
+mod history;
+mod image;
+mod keyboard;
+mod keymap;
+mod loading;
+mod messages;
+mod network;
+mod notify;
+mod offline;
+mod play_state;
+mod profile;
+mod royale;
+mod session_log;
+mod settings;
+mod setup;
+mod simulate;
+mod sound;
+mod stats;
+mod storage;
+mod sync;
+mod timer;
+mod tui;
+mod user_data;
+mod webhook;
+mod word_list;
 
-        let fetched_wl = fetch_word_list()?;
-        fs::write(".word-list.cache.txt", fetched_wl.join("\n"))?;
+use achievements::{Achievement, Achievements};
+use analysis::Analysis;
+use animation::Animations;
+use assist::{Assist, Panel};
+use autocomplete::Autocomplete;
+use bench::Strategy;
+use board::Board;
+use cli::Args;
+use clock::Timezone;
+use color_support::ColorSupport;
+use config::{Colors, Config};
+use definition::Definition;
+use event_loop::{Crossterm, Event, Events};
+use keyboard::Key;
+use keymap::{Action, Pending};
+use loading::{LoadRequest, Puzzle, PuzzleMeta};
+use messages::Messages;
+use play_state::PlayState;
+use settings::{Setting, Settings};
+use sound::{Effect, Sounds};
+use stats::{GameRecord, Stats};
+use sync::Leaderboard;
+use timer::{Deadline, Timer};
+use tui::{Terminal, Tui};
 
-        fetched_wl
-            .into_iter()
-            .map(|w| w.to_uppercase())
-            .collect::<HashSet<String>>()
-    };
+// --history on its own, without a game to go back to
+fn show_history(config: &Config) -> Result<()> {
+    let mut terminal = Tui::init(config.mouse, config.color_support)?;
+    let mut events = Crossterm::new(config.tick_rate());
+    history::run(
+        &mut terminal,
+        &mut events,
+        config,
+        &Stats::load(&config.paths.stats),
+    )?;
+    Ok(())
+}
 
-    if let Ok(play_cache) = fs::read_to_string(".play.state.txt") {
-        let mut lines = play_cache.lines().collect::<Vec<&str>>();
-        if !lines.is_empty() && lines.remove(0) == solution {
-            println!("you already played today\n{}", lines.join("\n"));
+fn main() -> Result<()> {
+    let mut args = Args::parse()?;
+    if let Some(path) = &args.log {
+        session_log::start(path, args.verbose)?;
+    }
+    // everything after this finds its files in the profile's folders
+    if profile::needs_picking(&args) {
+        match profile::pick()? {
+            profile::Choice::Default => {}
+            profile::Choice::Profile(name) => args.profile = Some(name),
+            profile::Choice::Quit => return Ok(()),
+        }
+    }
+    if let Some(name) = &args.profile {
+        profile::select(name.clone());
+    }
+    // asked before the config is read, so the answers apply straight away
+    if let Some(path) = setup::needed(&args) {
+        if !setup::run(&path)? {
             return Ok(());
         }
     }
+    let config = load_config(&args)?;
+    // files from before there were profiles were the default profile's
+    if args.profile.is_none() {
+        storage::migrate_legacy_files(&config.paths)?;
+    }
 
-    let mut terminal = ratatui::init();
-    let mut app = App {
-        solution: solution.to_owned().to_uppercase(),
-        word_list,
-        guesses: Vec::new(),
-        known_positions: HashMap::new(),
-        bad_characters: HashSet::new(),
-        current_guess_input: String::new(),
-        exit: false,
-    };
+    if let Some(command) = &args.user_data {
+        return user_data::run(command, &config, args.config.as_deref());
+    }
 
-    app.run(&mut terminal)?;
-    ratatui::restore();
+    let mut request = load_request(&args, &config)?;
 
-    let emojis = app
-        .guesses
-        .iter()
-        .map(|guess| {
-            guess
-                .iter()
-                .map(|(_, p)| p.unwrap_or(LetterPosition::None).emoji())
-                .collect::<String>()
-        })
-        .collect::<Vec<String>>();
+    if let Some(command) = args.wordlist.clone() {
+        return extra_words::run(command, &request);
+    }
+    if args.bench.is_some() || args.simulate.is_some() {
+        return run_solver(&args, &config, &request);
+    }
+
+    // loaded like it's about to be played, so a word that isn't in the list is caught here
+    if let Some(word) = &args.create_challenge {
+        let request = LoadRequest {
+            mode: GameMode::Challenge,
+            challenge: Some(word.clone()),
+            ..request
+        };
+        if args.offline {
+            Puzzle::offline(&request)?;
+        } else {
+            loading::load(&request, &request.agent, &|message| eprintln!("{message}"))?;
+        }
 
-    println!("{}", emojis.join("\n"));
+        let challenge = challenge::encode(word);
+        eprintln!("play it with wordle-tui --challenge {challenge}");
+        println!("{challenge}");
+        return Ok(());
+    }
 
-    if emojis.len() == 6 || // used all guesses
-        app.guesses.last().is_some_and(|guess|
-            guess.iter().all(|(_, p)| p == &Some(LetterPosition::Correct)) // got right answer
-        )
+    // no terminal to take over, progress goes to stderr so stdout is only the game
+    if args.no_tui {
+        let puzzle = if args.offline {
+            Puzzle::offline(&request)?
+        } else {
+            loading::load(&request, &request.agent, &|message| eprintln!("{message}"))?
+        };
+        return headless::run(&puzzle, config.hard_mode, args.json);
+    }
+
+    if args.history {
+        return show_history(&config);
+    }
+
+    // read before taking over the terminal so a bad word list is reported normally
+    let mut offline_puzzle = args
+        .offline
+        .then(|| Puzzle::offline(&request))
+        .transpose()?;
+
+    // restored when this goes out of scope too, so errors and panics don't leave the terminal
+    // in raw mode
+    let mut terminal = Tui::init(config.mouse, config.color_support)?;
+    let mut events = Crossterm::new(config.tick_rate());
+
+    // only goes around again with --wait, once the day rolls over, or with --daemon after
+    // every game
+    loop {
+        let puzzle = if let Some(puzzle) = offline_puzzle.take() {
+            puzzle
+        } else if args.offline {
+            Puzzle::offline(&request)?
+        } else {
+            let Some(puzzle) = loading::run(&mut terminal, &request)? else {
+                tui::restore();
+                return Ok(());
+            };
+            puzzle
+        };
+
+        if args.duel.is_some() || args.royale.is_some() {
+            return play_together(&mut terminal, &config, puzzle, &request, &args);
+        }
+
+        match play(
+            &mut terminal,
+            &mut events,
+            config.clone(),
+            puzzle,
+            &request,
+            &args,
+        )? {
+            Outcome::Quit => return Ok(()),
+            Outcome::NextPuzzle => {
+                request.date = config.timezone.today();
+                config.notify.send("wordle", "today's puzzle is out");
+            }
+            // loaded again, which finds it played and shows the countdown
+            Outcome::Idle => {}
+        }
+    }
+}
+
+// duels and battle royales, which have game loops of their own
+fn play_together(
+    terminal: &mut Terminal,
+    config: &Config,
+    puzzle: Puzzle,
+    request: &LoadRequest,
+    args: &Args,
+) -> Result<()> {
+    match (args.duel, &args.royale) {
+        (Some(kind), _) => duel::run(terminal, config, puzzle, request.word_length, kind),
+        (None, Some(room)) => royale::run(terminal, config, puzzle, room, &request.agent),
+        (None, None) => Ok(()),
+    }
+}
+
+// bench and simulate, which play by themselves. always on a local list, how a strategy does
+// shouldn't depend on the network
+fn run_solver(args: &Args, config: &Config, request: &LoadRequest) -> Result<()> {
+    let words = word_list::with_length(
+        request.word_list.offline().fetch_words(&request.agent)?,
+        request.word_length,
+    )?;
+    if let Some(strategy) = args.bench {
+        return bench::run(&words.accepted, strategy, config.hard_mode);
+    }
+
+    let strategies = match &args.simulate {
+        Some(strategies) if !strategies.is_empty() => strategies,
+        _ => &Strategy::ALL[..],
+    };
+    simulate::run(
+        &words.accepted,
+        &answers::pool(&words.accepted, &words.answers, config.answer_pool),
+        strategies,
+        args.games.unwrap_or(simulate::GAMES),
+        args.seed.unwrap_or_else(|| fastrand::u64(..)),
+        config.hard_mode,
+    )
+}
+
+// what to load, and how, for the mode the flags ask for
+fn load_request(args: &Args, config: &Config) -> Result<LoadRequest> {
+    // duels, battle royales and multi-board games are played on random words too, so they
+    // never give away the daily puzzle
+    let mode = if args.challenge.is_some() {
+        GameMode::Challenge
+    } else if args.absurdle {
+        GameMode::Absurdle
+    } else if args.practice
+        || args.duel.is_some()
+        || args.royale.is_some()
+        || args.boards.unwrap_or(1) > 1
+        || config.fill_in > 0
     {
-        // got correct answer, they can't play again today!
-        fs::write(
-            ".play.state.txt",
-            format!("{solution}\n{}", emojis.join("\n")),
-        )?;
+        GameMode::Practice
+    } else {
+        GameMode::Daily
+    };
+
+    Ok(LoadRequest {
+        mode,
+        date: config.timezone.today(),
+        timezone: config.timezone,
+        agent: network::agent(config)?,
+        word_list: args
+            .word_list
+            .clone()
+            .unwrap_or_else(|| config.word_list.clone()),
+        word_list_cache: config.paths.word_list_cache.clone(),
+        word_list_max_age: config.timeouts.word_list(),
+        word_list_extensions: config.paths.word_list_extensions.clone(),
+        refresh_word_list: args.refresh_word_list,
+        tick_rate: config.tick_rate(),
+        challenge: args.challenge.clone(),
+        seed: args.seed,
+        word_length: args.word_length(),
+        answer_pool: config.answer_pool,
+        attempts: config.network.attempts.max(1),
+        daily_cache: config.paths.daily_cache.clone(),
+        prefetch: config.network.prefetch,
+    })
+}
+
+// the config file with any flags that override it applied
+fn load_config(args: &Args) -> Result<Config> {
+    let mut config = Config::load(args.config.as_deref())?;
+    config.hard_mode |= args.hard_mode;
+    config.timer |= args.speedrun;
+    config.accessible |= args.accessible;
+    if let Some(letters) = args.fill_in {
+        config.fill_in = letters;
+    }
+    if args.no_color {
+        config.color_support = ColorSupport::None;
+    }
+    config.color_support = config.color_support.detect();
+    if config.color_support == ColorSupport::None {
+        config.colors = Colors::without_color();
     }
+    config.colors.symbols |= config.color_support.needs_symbols(&config.colors);
+    config.streamer.enabled |= args.streamer;
+    config.blitz.enabled |= args.blitz;
+    if args.utc {
+        config.timezone = Timezone::Utc;
+    }
+    if let Some(share_format) = args.share_format {
+        config.share_format = share_format;
+    }
+    if let Some(path) = &args.export_json {
+        config.paths.export_json = Some(path.clone());
+    }
+    if let Some(path) = &args.export_image {
+        config.paths.export_image = Some(path.clone());
+    }
+    // letters given away make it a practice game, and those have no next one to wait for
+    ensure!(
+        !args.wait || config.fill_in == 0,
+        "--wait and --daemon only work with the daily puzzle, set fill_in = 0 to use them"
+    );
 
-    Ok(())
+    Ok(config)
 }
 
-fn fetch_word_list() -> Result<Vec<String>> {
-    let res = ureq::get("https://www.nytimes.com/games-assets/v2/9673.7e73cdd39fb6121fa17d.js")
-        .call()?
-        .into_string()?;
+fn random_word(word_list: &HashSet<String>) -> Option<String> {
+    if word_list.is_empty() {
+        return None;
+    }
 
-    // [...noise] const o=[ *[WORD ARRAY]* ] [...noise]
-    let (array_json, _) = res
-        .split_once("const o=[")
-        .and_then(|(_, p)| p.split_once(']'))
-        .context("failed to split array string")?;
+    word_list
+        .iter()
+        .nth(fastrand::usize(..word_list.len()))
+        .cloned()
+}
 
-    serde_json::from_str::<Vec<String>>(&format!("[{array_json}]"))
-        .context("failed to parse array json")
+// a seeded game's words are the same for everyone, anything else is random
+fn practice_word(answers: &HashSet<String>, seed: Option<u64>, board: usize) -> Option<String> {
+    seed.map_or_else(
+        || random_word(answers),
+        |seed| answers::seeded(answers, seed, board),
+    )
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Copy)]
-enum LetterPosition {
-    None,
-    WrongPlacement,
-    Correct,
+// every extra board gets an extra guess, like quordle's 9 guesses for 4 boards
+fn new_game(solution: &str, hard_mode: bool, boards: usize, mode: GameMode) -> Game {
+    let max_guesses = if mode == GameMode::Absurdle {
+        ABSURDLE_GUESSES
+    } else {
+        MAX_GUESSES + boards - 1
+    };
+    Game::new(solution, hard_mode).with_max_guesses(max_guesses)
 }
 
-impl LetterPosition {
-    const fn emoji(self) -> char {
-        match self {
-            Self::None => '⬜',
-            Self::WrongPlacement => '🟨',
-            Self::Correct => '🟩',
-        }
+fn already_played(
+    terminal: &mut ratatui::Terminal<impl Backend>,
+    events: &mut impl Events,
+    config: &Config,
+    today: NaiveDate,
+    share: &str,
+    wait: bool,
+) -> Result<Outcome> {
+    if wait && countdown::run(terminal, events, config, today, share)? {
+        return Ok(Outcome::NextPuzzle);
     }
 
-    const fn color(self) -> Color {
-        match self {
-            Self::None => Color::DarkGray,
-            Self::WrongPlacement => Color::LightYellow,
-            Self::Correct => Color::LightGreen,
-        }
+    tui::restore();
+    println!(
+        "you already played today\n{share}\n\n{}",
+        countdown::message(config)
+    );
+    Ok(Outcome::Quit)
+}
+
+// a daily puzzle older than one already played means the clock's gone back, which is played
+// as an archive game or not at all
+fn check_clock(
+    terminal: &mut ratatui::Terminal<impl Backend>,
+    events: &mut impl Events,
+    config: &Config,
+    mode: GameMode,
+    today: NaiveDate,
+    stats: &Stats,
+    saved: Option<&PlayState>,
+) -> Result<Option<GameMode>> {
+    let Some(latest) = clock_skew::latest_played(stats, saved)
+        .filter(|&latest| mode == GameMode::Daily && latest > today)
+    else {
+        return Ok(Some(mode));
+    };
+
+    warn!("the clock says {today} but {latest}'s puzzle was already played");
+    if !clock_skew::run(terminal, events, config, today, latest)? {
+        tui::restore();
+        println!("{}", clock_skew::message(today, latest));
+        return Ok(None);
     }
+    Ok(Some(GameMode::Archive))
 }
 
-#[derive(Debug, Eq, PartialEq)]
-struct HashedLetterIndex(char, u8);
-macro_rules! impl_into_hli {
-    ($prim:ty) => {
-        impl From<(char, $prim)> for HashedLetterIndex {
-            fn from((letter, pos): (char, $prim)) -> Self {
-                #[allow(clippy::cast_possible_truncation)]
-                Self(letter, pos as u8)
-            }
-        }
+fn play(
+    terminal: &mut ratatui::Terminal<impl Backend>,
+    events: &mut impl Events,
+    config: Config,
+    puzzle: Puzzle,
+    request: &LoadRequest,
+    args: &Args,
+) -> Result<Outcome> {
+    let (mode, today) = (request.mode, request.date);
+    let solution = puzzle.solution.clone();
+
+    let saved = (mode == GameMode::Daily)
+        .then(|| PlayState::load(&config.paths.play_state))
+        .flatten();
+    let stats = Stats::load(&config.paths.stats);
+    let Some(mode) = check_clock(
+        terminal,
+        events,
+        &config,
+        mode,
+        today,
+        &stats,
+        saved.as_ref(),
+    )?
+    else {
+        return Ok(Outcome::Quit);
+    };
+    let saved = saved.filter(|state| mode == GameMode::Daily && state.is_for(&solution));
+
+    // older versions kept nothing but the share text once the game was over, so there's no
+    // board to show. --daemon goes to the countdown too, quitting the board would only bring
+    // it back
+    if let Some(share) = saved
+        .as_ref()
+        .filter(|state| state.guesses.is_empty() || args.daemon)
+        .and_then(|state| state.share.as_ref())
+    {
+        return already_played(terminal, events, &config, today, share, args.wait);
+    }
+    let played = saved.as_ref().is_some_and(|state| state.share.is_some());
+
+    let mut app = App::new(
+        config,
+        mode,
+        puzzle,
+        today,
+        stats,
+        request.agent.clone(),
+        args,
+    );
+    if let Some(state) = &saved {
+        app.resume(state);
+    }
+    if played {
+        app.show_played();
+    } else {
+        app.fill_in();
+    }
+    let outcome = app.run(terminal, events)?;
+
+    let finished = app.view != View::Playing;
+    let share = finished.then(|| app.share_text());
+    let outcome = if outcome == Outcome::Quit && finished && args.daemon {
+        Outcome::Idle
+    } else {
+        outcome
     };
+    // the terminal is kept for the next puzzle, so only print once it's actually handed back
+    if outcome == Outcome::Quit {
+        tui::restore();
+        println!("{}", share.as_deref().unwrap_or(&app.share_grid()));
+    }
+
+    // a game that was already over has nothing new to keep
+    if !played {
+        app.save_daily(&solution, share)?;
+    }
+    Ok(outcome)
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Outcome {
+    Quit,
+    // the date rolled over while waiting on the results, so tomorrow's puzzle is up
+    NextPuzzle,
+    // the game's over and --daemon sits on the countdown until the next one
+    Idle,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum GameMode {
+    Daily,
+    Practice,
+    // a word picked by a friend
+    Challenge,
+    // the solution keeps moving to dodge every guess, see `Game::evade`
+    Absurdle,
+    // the daily puzzle, played when the clock looks to have gone back so it doesn't count
+    Archive,
 }
-impl_into_hli!(u8);
-impl_into_hli!(usize);
 
-impl Hash for HashedLetterIndex {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        let letter_value = (self.0 as u8) - b'A';
-        state.write_u8((letter_value << 3) | self.1);
+impl GameMode {
+    // played on a word of its own each round, so there's always another one
+    const fn is_random(self) -> bool {
+        matches!(self, Self::Practice | Self::Absurdle)
     }
 }
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum View {
+    Playing,
+    Results {
+        copied: bool,
+        // today's daily was finished before this was opened, so the results are all there is
+        already_played: bool,
+    },
+}
+
+// with `keys.modal`, letters are only typed in insert mode, the way vim does it
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum InputMode {
+    Insert,
+    Normal,
+}
+
+// drawn over whatever view is underneath
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Popup {
+    Help,
+    Stats(stats::Tab),
+    Analysis,
+    Achievements,
+    Leaderboard,
+    Settings,
+    ConfirmQuit,
+    // a screen of its own rather than drawn over the game, see `run`
+    History,
+}
+
 #[derive(Debug)]
 struct App {
-    solution: String,
+    mode: GameMode,
+    // a single board unless it's a multi-board game, where every guess goes to each of them
+    boards: Vec<Board>,
+    input: String,
+    meta: Option<PuzzleMeta>,
     word_list: HashSet<String>,
+    // where new practice words and extra boards are picked from
+    answers: HashSet<String>,
 
-    guesses: Vec<Vec<(char, Option<LetterPosition>)>>,
-    known_positions: HashMap<HashedLetterIndex, LetterPosition>,
-    bad_characters: HashSet<char>,
+    date: NaiveDate,
+    stats: Stats,
+    achievements: Achievements,
+    // unlocked by the game that just ended, announced once its last row has been revealed
+    unlocked: Vec<Achievement>,
+    config: Config,
+    animations: Animations,
+    sounds: Sounds,
+    messages: Messages,
+    timer: Timer,
+    // how long each guess took to come up with, starting from the first keypress
+    think_times: Vec<Duration>,
+    // the time left for the current row in blitz mode
+    deadline: Option<Deadline>,
+    speedrun: bool,
+    // where seeded practice words come from, moving on by one every round
+    seed: Option<u64>,
+    // stay on the results until the next daily puzzle comes out
+    wait: bool,
+    agent: Agent,
+    webhook: webhook::Status,
+    leaderboard: Leaderboard,
+    definition: Definition,
+    offline: bool,
+    settings: Settings,
+    // where changed settings are written back to
+    config_path: Option<PathBuf>,
+    assist: Assist,
+    autocomplete: Autocomplete,
+    // what the terminal's title was last set to
+    terminal_title: String,
 
-    current_guess_input: String,
-
-    exit: bool,
+    view: View,
+    popup: Option<Popup>,
+    // how the finished game went, once it's been asked for
+    analysis: Option<Analysis>,
+    // where the on-screen keyboard was last drawn, if it's showing, for mouse clicks
+    keyboard_area: Cell<Option<Rect>>,
+    // how far down the popup is, kept to what it has to show each time it's drawn
+    scroll: Cell<u16>,
+    pending: Pending,
+    input_mode: InputMode,
+    // set once it's time to stop, and why
+    exit: Option<Outcome>,
 }
 
 impl App {
-    fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<()> {
-        while !self.exit {
+    // a fresh game on the puzzle's word, see `resume` for picking up a saved one
+    fn new(
+        config: Config,
+        mode: GameMode,
+        puzzle: Puzzle,
+        today: NaiveDate,
+        stats: Stats,
+        agent: Agent,
+        args: &Args,
+    ) -> Self {
+        // the rest of the boards get words of their own
+        let count = args.boards.unwrap_or(1);
+        let answers = answers::pool(&puzzle.word_list, &puzzle.answers, config.answer_pool);
+        let boards = iter::once(puzzle.solution)
+            .chain((1..count).filter_map(|board| practice_word(&answers, args.seed, board)))
+            .map(|solution| Board::new(new_game(&solution, config.hard_mode, count, mode)))
+            .collect();
+
+        let input_mode = if config.keys.modal {
+            InputMode::Normal
+        } else {
+            InputMode::Insert
+        };
+        Self {
+            mode,
+            boards,
+            input: String::new(),
+            meta: puzzle.meta,
+            word_list: puzzle.word_list,
+            answers,
+            date: today,
+            stats,
+            achievements: Achievements::load(&config.paths.achievements),
+            unlocked: Vec::new(),
+            animations: Animations::new(config.animations)
+                .with_reveal_delay(config.streamer.reveal_delay()),
+            sounds: Sounds::default(),
+            messages: Messages::default(),
+            timer: Timer::new(Duration::ZERO),
+            think_times: Vec::new(),
+            deadline: config.blitz.limit().map(Deadline::new),
+            speedrun: args.speedrun,
+            seed: args.seed,
+            wait: args.wait,
+            agent,
+            webhook: webhook::Status::Off,
+            leaderboard: Leaderboard::NotSynced,
+            definition: Definition::Off,
+            offline: args.offline,
+            settings: Settings::default(),
+            config_path: args.config.clone().or_else(Config::default_path),
+            assist: Assist::Unused,
+            autocomplete: Autocomplete::default(),
+            terminal_title: String::new(),
+            config,
+            view: View::Playing,
+            popup: None,
+            analysis: None,
+            keyboard_area: Cell::new(None),
+            scroll: Cell::new(0),
+            pending: Pending::default(),
+            input_mode,
+            exit: None,
+        }
+    }
+
+    // pick up where an unfinished game for the same puzzle left off
+    fn resume(&mut self, state: &PlayState) {
+        let game = &self.board().game;
+        let (solution, max_guesses) = (game.solution().to_owned(), game.max_guesses());
+        self.boards[0] = Board {
+            game: Game::restore(&solution, state.hard_mode, max_guesses, &state.guesses),
+            hints: state
+                .hints
+                .iter()
+                .filter_map(|hint| hints::decode(hint))
+                .collect(),
+            revealed: Vec::new(),
+        };
+        self.timer = Timer::new(state.elapsed);
+        self.think_times = state.think_times();
+        self.assist = Assist::restored(state.assisted);
+    }
+
+    // daily games, play state and the assist panel only ever deal with one board
+    fn board(&self) -> &Board {
+        &self.boards[0]
+    }
+
+    // what the assist panel and analysis rank, the words answers are picked from rather than
+    // everything that's accepted. the nyt's answer isn't always one of its listed answers, so
+    // it's every word when it isn't
+    fn possible_answers(&self) -> &HashSet<String> {
+        if self.answers.contains(self.board().game.solution()) {
+            &self.answers
+        } else {
+            &self.word_list
+        }
+    }
+
+    // words the guess being typed could finish as. with a panel open only the ones that still
+    // fit are offered, best first, since it's been peeked at anyway
+    fn completions(&self) -> Vec<&str> {
+        // suggestions would give away the dots
+        if !self.config.autocomplete || self.config.streamer.enabled {
+            return Vec::new();
+        }
+
+        if self.boards.len() == 1 && matches!(self.assist, Assist::Visible(_)) {
+            let candidates = self.board().candidates(self.possible_answers());
+            return autocomplete::matching(candidates, &self.input);
+        }
+        let mut words =
+            autocomplete::matching(self.word_list.iter().map(String::as_str), &self.input);
+        words.sort_unstable();
+        words
+    }
+
+    fn hard_mode(&self) -> bool {
+        self.board().game.hard_mode()
+    }
+
+    fn is_finished(&self) -> bool {
+        self.boards
+            .iter()
+            .all(|board| board.game.status() != GameStatus::InProgress)
+    }
+
+    // how many guesses the whole game took, every board stops at the same count once solved
+    fn guesses_used(&self) -> usize {
+        self.boards
+            .iter()
+            .map(|board| board.game.guesses().len())
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut ratatui::Terminal<impl Backend>,
+        events: &mut impl Events,
+    ) -> Result<Outcome> {
+        loop {
+            if let Some(outcome) = self.exit {
+                return Ok(outcome);
+            }
+
+            // takes over the terminal until it's closed, then the results are back as they were
+            if self.popup == Some(Popup::History) {
+                self.popup = None;
+                if history::run(terminal, events, &self.config, &self.stats)? {
+                    self.exit = Some(Outcome::Quit);
+                }
+                continue;
+            }
+
+            self.update_terminal_title();
             terminal.draw(|frame| self.draw(frame))?;
-            self.handle_events()?;
+            self.handle_event(events.next()?);
         }
+    }
 
-        Ok(())
+    fn handle_event(&mut self, event: Event) {
+        match event {
+            Event::Key(key_event) => {
+                let (popup, view) = (self.popup, self.view);
+                self.handle_key_event(key_event);
+                if self.popup != popup {
+                    debug!("popup {popup:?} -> {:?}", self.popup);
+                    self.scroll.set(0);
+                }
+                if self.view != view {
+                    debug!("view {view:?} -> {:?}", self.view);
+                }
+            }
+            Event::Mouse(mouse_event) => self.handle_mouse_event(mouse_event),
+            Event::Paste(text) => self.paste(&text),
+            Event::Tick => self.tick(),
+        }
     }
 
-    fn handle_events(&mut self) -> Result<()> {
-        let e = event::read()?;
-        let Event::Key(key_event) = e else {
-            return Ok(());
+    // everything that moves on with time rather than with a key press
+    fn tick(&mut self) {
+        self.sounds.tick(&self.config.sound, &self.animations);
+        // they'd give the result away before the tiles do
+        if !self.animations.is_revealing() {
+            for achievement in self.unlocked.drain(..) {
+                self.messages
+                    .notify(format!("achievement unlocked: {}", achievement.name()));
+            }
+        }
+
+        self.definition.poll();
+        match self.webhook.poll() {
+            Some(Ok(())) => self.messages.notify("posted to the webhook"),
+            Some(Err(err)) => self.messages.push(format!("couldn't post: {err}")),
+            None => {}
+        }
+        if let Some(err) = self.leaderboard.poll() {
+            self.messages.push(format!("couldn't sync: {err}"));
+        }
+        self.check_deadline();
+        self.messages.tick();
+
+        // an unfinished game is left alone, it can still be played past midnight
+        if self.wait && self.view != View::Playing && self.config.timezone.today() != self.date {
+            self.exit = Some(Outcome::NextPuzzle);
+        }
+    }
+
+    // clicks on the on-screen keyboard do the same as pressing that key
+    fn handle_mouse_event(&mut self, mouse_event: MouseEvent) {
+        if mouse_event.kind != MouseEventKind::Down(MouseButton::Left) || self.popup.is_some() {
+            return;
+        }
+
+        let Some(key) = self.keyboard_area.get().and_then(|area| {
+            keyboard::key_at(
+                area,
+                self.config.keyboard_layout,
+                mouse_event.column,
+                mouse_event.row,
+            )
+        }) else {
+            return;
         };
 
-        if key_event.kind == KeyEventKind::Press {
-            self.handle_key_event(key_event);
+        match key {
+            Key::Letter(c) => {
+                let word_length = self.board().game.word_length();
+                if board::type_letter(&mut self.input, c, word_length) {
+                    self.timer.start();
+                }
+            }
+            Key::Enter => self.submit_guess(),
+            Key::Backspace => {
+                let _ = self.input.pop();
+            }
+        }
+    }
+
+    fn paste(&mut self, text: &str) {
+        if self.popup.is_some() || !matches!(self.view, View::Playing) {
+            return;
         }
 
-        Ok(())
+        let word_length = self.board().game.word_length();
+        if board::paste(&mut self.input, text, word_length) {
+            self.timer.start();
+        } else {
+            self.messages.push("there were no letters to paste");
+        }
     }
 
     fn handle_key_event(&mut self, key_event: KeyEvent) {
-        if key_event.modifiers == KeyModifiers::CONTROL && key_event.code == KeyCode::Char('c') {
-            self.exit = true;
+        if self.popup == Some(Popup::Settings) && self.handle_settings_key_event(key_event) {
+            return;
+        }
+
+        let keys = &self.config.keys;
+
+        if self.popup == Some(Popup::ConfirmQuit) {
+            match key_event.code {
+                KeyCode::Char('y') | KeyCode::Enter => self.exit = Some(Outcome::Quit),
+                // quit pressed twice is as good as a yes
+                _ if keys.quit.matches(key_event) => self.exit = Some(Outcome::Quit),
+                KeyCode::Char('n') => self.popup = None,
+                _ if keys.close.matches(key_event) => self.popup = None,
+                _ => {}
+            }
+            return;
+        }
+
+        if let Some(Popup::Stats(tab)) = self.popup {
+            if let Some(tab) = tab.switch(key_event.code) {
+                self.popup = Some(Popup::Stats(tab));
+                return;
+            }
+        }
+
+        if let Some(popup) = self.popup {
+            let toggle = match popup {
+                Popup::Help => Action::Help,
+                Popup::Stats(_) => Action::Stats,
+                Popup::Analysis => Action::Analysis,
+                Popup::Achievements => Action::Achievements,
+                Popup::Leaderboard => Action::Leaderboard,
+                Popup::Settings => Action::Settings,
+                // neither stays open long enough to get here, see above and `run`
+                Popup::History | Popup::ConfirmQuit => Action::History,
+            };
+            // the help is there during a game too, where the settings key would just be typed
+            let actions: &[Action] = if popup == Popup::Help {
+                &[Action::Close, toggle, Action::Quit, Action::Settings]
+            } else {
+                &[Action::Close, toggle, Action::Quit]
+            };
+            let scrolls = [
+                Action::ScrollUp,
+                Action::ScrollDown,
+                Action::Top,
+                Action::Bottom,
+            ];
+            let scroll = self.scroll.get();
+            match self
+                .pending
+                .action(keys, key_event, &[actions, &scrolls].concat())
+            {
+                Some(Action::Quit) => self.quit(),
+                Some(Action::Settings) if popup == Popup::Help => {
+                    self.popup = Some(Popup::Settings);
+                }
+                Some(Action::ScrollUp) => self.scroll.set(scroll.saturating_sub(1)),
+                Some(Action::ScrollDown) => self.scroll.set(scroll.saturating_add(1)),
+                Some(Action::Top) => self.scroll.set(0),
+                // brought back to the last line when it's drawn
+                Some(Action::Bottom) => self.scroll.set(u16::MAX),
+                Some(_) => self.popup = None,
+                None => {}
+            }
+            return;
+        }
+
+        if let View::Results { .. } = self.view {
+            self.handle_results_key_event(key_event);
+            return;
+        }
+
+        if self.input_mode == InputMode::Normal {
+            self.handle_normal_key_event(key_event);
+            return;
+        }
+
+        if self.handle_completion_key_event(key_event) {
             return;
         }
 
+        let action = self.config.keys.action(
+            key_event,
+            &[
+                Action::Normal,
+                Action::Quit,
+                Action::Help,
+                Action::Assist,
+                Action::LetterFrequencies,
+                Action::Hint,
+                Action::ToggleHardMode,
+                Action::Submit,
+                Action::Backspace,
+                Action::ClearInput,
+            ],
+        );
+        if action.is_some_and(|action| self.game_action(action)) {
+            return;
+        }
+
+        if let KeyCode::Char(c) = key_event.code {
+            let word_length = self.board().game.word_length();
+            if board::type_letter(&mut self.input, c, word_length) {
+                self.timer.start();
+            }
+        }
+    }
+
+    // with `keys.modal`, while letters aren't being typed they're free for everything that
+    // otherwise only works from the results
+    fn handle_normal_key_event(&mut self, key_event: KeyEvent) {
+        let action = self.pending.action(
+            &self.config.keys,
+            key_event,
+            &[
+                Action::Insert,
+                Action::Quit,
+                Action::Help,
+                Action::Stats,
+                Action::History,
+                Action::Achievements,
+                Action::Settings,
+                Action::Assist,
+                Action::LetterFrequencies,
+                Action::Hint,
+                Action::ToggleHardMode,
+                Action::Submit,
+                Action::Backspace,
+                Action::ClearInput,
+            ],
+        );
+        if let Some(action) = action {
+            let _ = self.game_action(action);
+        }
+    }
+
+    // returns whether the action did anything here, a letter key that didn't is typed instead
+    fn game_action(&mut self, action: Action) -> bool {
+        match action {
+            Action::Insert => self.input_mode = InputMode::Insert,
+            Action::Normal if self.config.keys.modal => self.input_mode = InputMode::Normal,
+            Action::Quit => self.quit(),
+            Action::Help => self.popup = Some(Popup::Help),
+            // practice rounds aren't in the stats
+            Action::Stats if self.mode == GameMode::Daily => {
+                self.popup = Some(Popup::Stats(stats::Tab::default()));
+            }
+            Action::History => self.popup = Some(Popup::History),
+            Action::Achievements => self.popup = Some(Popup::Achievements),
+            Action::Settings => self.popup = Some(Popup::Settings),
+            Action::Assist if self.boards.len() == 1 && self.config.assist => {
+                self.assist = self.assist.toggle(Panel::Words);
+            }
+            Action::LetterFrequencies if self.boards.len() == 1 && self.config.assist => {
+                self.assist = self.assist.toggle(Panel::Letters);
+            }
+            Action::Hint => self.hint(),
+            // hard mode can only be changed before any hints are revealed
+            Action::ToggleHardMode => {
+                let hard_mode = !self.hard_mode();
+                for board in &mut self.boards {
+                    let _ = board.game.set_hard_mode(hard_mode);
+                }
+            }
+            Action::Submit => self.submit_guess(),
+            Action::Backspace => {
+                let _ = self.input.pop();
+            }
+            Action::ClearInput => self.input.clear(),
+            _ => return false,
+        }
+
+        true
+    }
+
+    // only while there's something to finish, so tab still opens the panel otherwise
+    fn handle_completion_key_event(&mut self, key_event: KeyEvent) -> bool {
+        let completions = self.completions();
+        let Some(word) = self.autocomplete.suggestion(&self.input, &completions) else {
+            return false;
+        };
+
+        let count = completions.len();
+        match key_event.code {
+            _ if self
+                .config
+                .keys
+                .action(key_event, &[Action::Complete])
+                .is_some() =>
+            {
+                self.input = word.to_owned();
+            }
+            KeyCode::Up => self.autocomplete.previous(&self.input, count),
+            KeyCode::Down => self.autocomplete.next(&self.input, count),
+            _ => return false,
+        }
+
+        true
+    }
+
+    // returns whether the key was taken, anything else goes on to close the popup as usual
+    fn handle_settings_key_event(&mut self, key_event: KeyEvent) -> bool {
         match key_event.code {
-            KeyCode::Enter => self.submit_guess(),
-            KeyCode::Backspace => {
-                let _ = self.current_guess_input.pop();
+            KeyCode::Up | KeyCode::Char('k') => self.settings.up(),
+            KeyCode::Down | KeyCode::Char('j') => self.settings.down(),
+            KeyCode::Char(' ') | KeyCode::Left | KeyCode::Right => {
+                match self
+                    .settings
+                    .change(&mut self.config, self.config_path.as_deref())
+                {
+                    Ok(setting) => self.apply_setting(setting),
+                    Err(err) => self
+                        .messages
+                        .push(format!("couldn't save settings: {err:#}")),
+                }
+            }
+            _ => return false,
+        }
+
+        true
+    }
+
+    // most settings are read straight from the config as they're needed, these aren't
+    fn apply_setting(&mut self, setting: Setting) {
+        match setting {
+            // too late for a game that's underway, it's for the next one then
+            Setting::HardMode => {
+                for board in &mut self.boards {
+                    let _ = board.game.set_hard_mode(self.config.hard_mode);
+                }
             }
-            KeyCode::Char(c) => {
-                if self.current_guess_input.len() < 5 && c.is_alphabetic() {
-                    self.current_guess_input.push(c.to_ascii_uppercase());
+            Setting::Animations => self.animations.set_enabled(self.config.animations),
+            Setting::Assist if !self.config.assist => {
+                if let Assist::Visible(_) = self.assist {
+                    self.assist = Assist::Hidden;
                 }
             }
             _ => {}
         }
     }
 
+    // a game that's been started asks first, a stray ctrl+c shouldn't end it
+    fn quit(&mut self) {
+        let started = !self.input.is_empty() || self.guesses_used() > 0;
+        if self.config.confirm_quit && self.view == View::Playing && started {
+            self.popup = Some(Popup::ConfirmQuit);
+        } else {
+            self.exit = Some(Outcome::Quit);
+        }
+    }
+
     fn submit_guess(&mut self) {
-        if self.current_guess_input.len() != 5
-            || !self.word_list.contains(&self.current_guess_input)
+        // a guess has to be playable on every board that's still going before it goes anywhere
+        let mut playing = self
+            .boards
+            .iter_mut()
+            .filter(|board| board.game.status() == GameStatus::InProgress)
+            .collect::<Vec<&mut Board>>();
+        if let Some(err) = playing
+            .iter()
+            .find_map(|board| board.game.check(&self.input, &self.word_list).err())
         {
+            debug!(guess = %self.input, "rejected: {err}");
+            self.animations.shake();
+            self.config.sound.play(Effect::Invalid);
+            // most of these are a slipped finger, so the word they were going for is offered.
+            // not while streaming, it would show chat what was typed
+            let suggestion = (err == GuessError::NotInWordList && !self.config.streamer.enabled)
+                .then(|| wordle_core::suggest(&self.input, &self.word_list))
+                .flatten();
+            self.messages.push(suggestion.map_or_else(
+                || err.to_string(),
+                |word| format!("{err}, did you mean {word}?"),
+            ));
+            return;
+        }
+
+        for (index, board) in playing.iter_mut().enumerate() {
+            let _score = debug_span!("score", board = index).entered();
+            let guess = if self.mode == GameMode::Absurdle {
+                board.evade(&self.input, &self.word_list, &self.answers)
+            } else {
+                board.submit(&self.input, &self.word_list)
+            };
+            // what each letter was marked, for reports of a wrong yellow or green
+            if let Ok(guess) = guess {
+                debug!(
+                    guess = %self.input.to_uppercase(),
+                    solution = board.game.solution(),
+                    states = ?guess.letters().map(|(_, state)| state).collect::<Vec<_>>(),
+                    "scored"
+                );
+            }
+        }
+        self.row_played();
+    }
+
+    // a row's clock only starts once it's up for guessing, after the last one has flipped
+    fn check_deadline(&mut self) {
+        let Some(deadline) = &mut self.deadline else {
+            return;
+        };
+        if self.view != View::Playing || self.animations.is_revealing() {
+            return;
+        }
+
+        deadline.start();
+        if !deadline.expired() {
             return;
         }
 
-        let g = mem::take(&mut self.current_guess_input);
+        for board in &mut self.boards {
+            let _ = board.game.forfeit();
+        }
+        self.messages.push("out of time");
+        self.row_played();
+    }
 
-        let mut parsed_guess = g
-            .chars()
-            .map(|c| (c, None))
-            .collect::<Vec<(char, Option<LetterPosition>)>>();
+    fn row_played(&mut self) {
+        self.input.clear();
+        let elapsed = self.timer.elapsed();
+        self.think_times
+            .push(elapsed.saturating_sub(self.think_times.iter().sum()));
+        if let Some(deadline) = &mut self.deadline {
+            deadline.stop();
+        }
 
-        let contains_letter = |letter| self.solution.contains(letter);
+        let guesses = self.guesses_used();
+        let word_length = self.board().game.word_length();
+        self.animations.reveal(guesses - 1, word_length);
+        self.sounds.reveal(guesses - 1, word_length);
 
-        for (index, letter) in g.char_indices() {
-            // add to bad characters if irrelevant
-            if !contains_letter(letter) {
-                self.bad_characters.insert(letter);
-                continue;
+        if self.is_finished() {
+            self.finish(guesses);
+        }
+    }
+
+    // everything that happens once, when the last board is done
+    fn finish(&mut self, guesses: usize) {
+        self.timer.stop();
+
+        let won = self
+            .boards
+            .iter()
+            .all(|board| board.game.status() == GameStatus::Won);
+        info!(
+            "{:?} game {} in {guesses} after {:?}",
+            self.mode,
+            if won { "won" } else { "lost" },
+            self.timer.elapsed()
+        );
+        if won {
+            self.sounds.after_reveal(Effect::Win);
+        }
+        // nothing that gives the word away, the notification could be seen by anyone nearby
+        self.config.notify.send(
+            &self.title(),
+            &if won {
+                format!("solved in {guesses}/{}", self.board().game.max_guesses())
+            } else {
+                String::from("out of guesses")
+            },
+        );
+
+        if self.mode == GameMode::Daily {
+            #[allow(clippy::cast_possible_truncation)]
+            self.stats.record(GameRecord {
+                date: self.date,
+                won: self.board().game.status() == GameStatus::Won,
+                guesses: guesses as u8,
+                duration: Some(self.timer.elapsed()),
+                answer: Some(self.board().game.solution().to_owned()),
+                words: self
+                    .board()
+                    .game
+                    .guesses()
+                    .iter()
+                    .map(|guess| guess.word.clone())
+                    .collect(),
+                think_times: self.think_times.clone(),
+            });
+        }
+        // peeking at the answers doesn't earn anything
+        if self.boards.len() == 1 && !self.assist.used() && self.board().hints.is_empty() {
+            match self.achievements.unlock(
+                &self.boards[0].game,
+                self.timer.elapsed(),
+                &self.stats,
+                self.date,
+            ) {
+                Ok(unlocked) => self.unlocked = unlocked,
+                Err(err) => self
+                    .messages
+                    .push(format!("couldn't save achievements: {err:#}")),
+            }
+        }
+        if let Some(path) = &self.config.paths.export_json {
+            let record = self.export_record().to_string();
+            if let Err(err) = storage::append_line(path, &record) {
+                self.messages
+                    .push(format!("couldn't export results: {err:#}"));
+            }
+        }
+        if let Some(path) = &self.config.paths.export_image {
+            if let Err(err) = storage::write(path, self.image()) {
+                self.messages
+                    .push(format!("couldn't export the image: {err:#}"));
+            }
+        }
+        // only daily results are worth telling the group chat about
+        if self.mode == GameMode::Daily && self.config.webhook.url.is_some() {
+            if self.config.webhook.confirm {
+                self.webhook = webhook::Status::Asking;
+            } else {
+                self.post_result();
             }
+        }
+        if self.mode == GameMode::Daily {
+            self.sync_leaderboard(true);
+        }
+        // there's no room to show more than one
+        if self.boards.len() == 1 {
+            let online = self.config.network.definitions && !self.offline;
+            self.definition = Definition::look_up(
+                self.board().game.solution(),
+                online.then_some(&self.agent),
+                self.config.paths.dictionary.as_ref(),
+            );
+        }
+        self.view = View::Results {
+            copied: false,
+            already_played: false,
+        };
+    }
 
-            if self.solution.as_bytes()[index] == letter as u8 {
-                parsed_guess[index].1 = Some(LetterPosition::Correct);
-                continue;
+    // with `push` this game's result goes up too, otherwise it's only a refresh
+    fn sync_leaderboard(&mut self, push: bool) {
+        let Some(client) = sync::Client::new(&self.config.sync, &self.agent) else {
+            return;
+        };
+
+        #[allow(clippy::cast_possible_truncation)]
+        let entry = push.then(|| sync::Entry {
+            name: self.config.sync.name(),
+            won: self.board().game.status() == GameStatus::Won,
+            guesses: self.guesses_used() as u8,
+            hard_mode: self.hard_mode(),
+        });
+        self.leaderboard = Leaderboard::sync(client, self.date, entry);
+    }
+
+    fn post_result(&mut self) {
+        self.webhook = webhook::Status::post(
+            &self.config.webhook,
+            &self.agent,
+            &self.share_text(),
+            &self.export_record(),
+        );
+    }
+
+    fn handle_results_key_event(&mut self, key_event: KeyEvent) {
+        let keys = &self.config.keys;
+        if keys.quit.matches(key_event) {
+            self.exit = Some(Outcome::Quit);
+            return;
+        }
+
+        // let the last row finish flipping before anything can be skipped
+        if self.animations.is_revealing() {
+            return;
+        }
+
+        if self.webhook.is_asking() {
+            match key_event.code {
+                KeyCode::Char('y') => self.post_result(),
+                KeyCode::Char('n') | KeyCode::Esc => self.webhook = webhook::Status::Off,
+                _ => {}
             }
+            return;
         }
 
-        for (index, letter) in g.char_indices() {
-            if !contains_letter(letter) || self.solution.as_bytes()[index] == letter as u8 {
-                continue;
+        let action = keys.action(
+            key_event,
+            &[
+                Action::Help,
+                Action::Stats,
+                Action::Analysis,
+                Action::History,
+                Action::Achievements,
+                Action::Leaderboard,
+                Action::Settings,
+                Action::Copy,
+                Action::NewRound,
+                Action::Close,
+            ],
+        );
+        match action {
+            Some(Action::Help) => self.popup = Some(Popup::Help),
+            // practice rounds aren't in the stats
+            Some(Action::Stats) if self.mode == GameMode::Daily => {
+                self.popup = Some(Popup::Stats(stats::Tab::default()));
+            }
+            Some(Action::History) => self.popup = Some(Popup::History),
+            Some(Action::Achievements) => self.popup = Some(Popup::Achievements),
+            Some(Action::Settings) => self.popup = Some(Popup::Settings),
+            Some(Action::Leaderboard)
+                if self.mode == GameMode::Daily && self.config.sync.url.is_some() =>
+            {
+                if self.leaderboard.needs_sync() {
+                    self.sync_leaderboard(false);
+                }
+                self.popup = Some(Popup::Leaderboard);
+            }
+            // worked out once per game, it goes over the whole word list for every guess
+            Some(Action::Analysis) if self.boards.len() == 1 => {
+                if self.analysis.is_none() {
+                    self.analysis = Some(analysis::analyze(
+                        &self.board().game,
+                        self.possible_answers(),
+                    ));
+                }
+                self.popup = Some(Popup::Analysis);
+            }
+            Some(Action::Copy) => {
+                let copied = clipboard::copy(&self.share_text()).is_ok();
+                if let View::Results { copied: shown, .. } = &mut self.view {
+                    *shown = copied;
+                }
             }
+            Some(Action::NewRound) if self.mode.is_random() => self.new_round(),
+            Some(Action::Close) => self.exit = Some(Outcome::Quit),
+            _ => {}
+        }
+    }
+
+    fn save_daily(&self, solution: &str, share: Option<String>) -> Result<()> {
+        // practice rounds and challenges don't count towards anything, and there's nothing to
+        // resume if no guesses were made
+        if self.mode != GameMode::Daily || self.board().game.guesses().is_empty() {
+            return Ok(());
+        }
+
+        if share.is_some() {
+            self.stats.save()?;
+        }
+
+        // once the game is finished they can't play again today!
+        self.play_state(solution, share)
+            .save(&self.config.paths.play_state)
+    }
 
-            let solution_letter_occurrences =
-                self.solution.chars().filter(|c| c == &letter).count();
-            let existing_letter_occurrences = parsed_guess
+    // what's kept of a daily game, with the share text once it's finished
+    fn play_state(&self, solution: &str, share: Option<String>) -> PlayState {
+        PlayState {
+            solution_hash: play_state::hash_solution(solution),
+            date: Some(self.date),
+            hard_mode: self.board().game.hard_mode(),
+            elapsed: self.timer.elapsed(),
+            assisted: self.assist.used(),
+            hints: self.board().hints.iter().map(hints::encode).collect(),
+            // kept once it's over too, so the finished board can be shown again. they're
+            // written obscured, see `PlayState::guesses`
+            guesses: self
+                .board()
+                .game
+                .guesses()
                 .iter()
-                .filter(|(c, m)| c == &letter && m.is_some())
-                .count();
+                .map(|guess| guess.word.clone())
+                .collect(),
+            think_secs: self.think_times.iter().map(Duration::as_secs).collect(),
+            share,
+        }
+    }
+
+    // absurdle has no answer to give away until the end, and multi-board games have no room
+    // to show what was given
+    fn hint(&mut self) {
+        if self.config.hints == 0 || self.boards.len() > 1 || self.mode == GameMode::Absurdle {
+            return;
+        }
+        if self.board().hints.len() >= self.config.hints {
+            self.messages.push("no hints left");
+            return;
+        }
 
-            if solution_letter_occurrences > existing_letter_occurrences {
-                parsed_guess[index].1 = Some(LetterPosition::WrongPlacement);
+        let alphabet = hints::alphabet(&self.word_list);
+        let board = &mut self.boards[0];
+        match board
+            .game
+            .hint(&board.given(), &alphabet, &mut fastrand::Rng::new())
+        {
+            Some(hint) => {
+                self.messages.notify(hints::describe(&hint));
+                board.hints.push(hint);
+                self.timer.start();
             }
+            None => self.messages.push("there's nothing left to give away"),
         }
+    }
 
-        // finally use the learned information to add to knowledge base
-        parsed_guess
-            .iter()
-            .enumerate()
-            .filter_map(|(i, &(l, pos_opt))| pos_opt.map(|pos| (i, (l, pos))))
-            .for_each(|(index, (letter, position))| {
-                self.known_positions.insert((letter, index).into(), position);
+    // today's finished game as it was left, only to look at
+    fn show_played(&mut self) {
+        self.timer.stop();
+        self.view = View::Results {
+            copied: false,
+            already_played: true,
+        };
+    }
+
+    // the same letters for the same seed, like the words themselves
+    fn fill_in(&mut self) {
+        if self.config.fill_in == 0 || self.mode == GameMode::Absurdle {
+            return;
+        }
+        for (i, board) in self.boards.iter_mut().enumerate() {
+            let mut rng = self.seed.map_or_else(fastrand::Rng::new, |seed| {
+                fastrand::Rng::with_seed(seed.wrapping_add(i as u64))
             });
+            board.reveal(self.config.fill_in, &mut rng);
+        }
+    }
 
-        self.guesses.push(parsed_guess);
+    fn new_round(&mut self) {
+        self.seed = self.seed.map(|seed| seed.wrapping_add(1));
+        let solutions = (0..self.boards.len())
+            .map(|board| practice_word(&self.answers, self.seed, board))
+            .collect::<Option<Vec<String>>>();
+        if let Some(solutions) = solutions {
+            let (hard_mode, boards) = (self.hard_mode(), solutions.len());
+            self.boards = solutions
+                .iter()
+                .map(|solution| Board::new(new_game(solution, hard_mode, boards, self.mode)))
+                .collect();
+            self.fill_in();
+        }
+        self.timer = Timer::new(Duration::ZERO);
+        self.think_times.clear();
 
-        if self.solution.eq_ignore_ascii_case(&g) || self.guesses.len() == 6 {
-            self.exit = true;
+        if self.assist == Assist::Hidden {
+            self.assist = Assist::Unused;
         }
+        self.analysis = None;
+        self.view = View::Playing;
+    }
+
+    // one grid per board, each separated by a blank line
+    fn share_grid(&self) -> String {
+        self.boards
+            .iter()
+            .map(|board| {
+                board
+                    .game
+                    .guesses()
+                    .iter()
+                    .map(|guess| {
+                        guess
+                            .states
+                            .iter()
+                            .map(|&state| self.config.share_tile(state))
+                            .collect::<String>()
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            })
+            .collect::<Vec<String>>()
+            .join("\n\n")
+    }
+
+    fn export_record(&self) -> serde_json::Value {
+        serde_json::json!({
+            "puzzle": self.meta.as_ref().map(|meta| serde_json::json!({
+                "id": meta.id,
+                "number": meta.number,
+                "editor": meta.editor,
+            })),
+            "date": self.date.to_string(),
+            "mode": match self.mode {
+                GameMode::Daily => "daily",
+                GameMode::Practice => "practice",
+                GameMode::Challenge => "challenge",
+                GameMode::Absurdle => "absurdle",
+                GameMode::Archive => "archive",
+            },
+            "seed": self.seed,
+            "hard_mode": self.hard_mode(),
+            "won": self.boards.iter().all(|board| board.game.status() == GameStatus::Won),
+            "guess_count": self.guesses_used(),
+            "duration_secs": self.timer.elapsed().as_secs(),
+            "think_secs": self
+                .think_times
+                .iter()
+                .map(Duration::as_secs)
+                .collect::<Vec<u64>>(),
+            "assisted": self.assist.used(),
+            "hints": self.board().hints.len(),
+            "boards": self
+                .boards
+                .iter()
+                .map(|board| export::game_json(&board.game, !self.config.streamer.enabled))
+                .collect::<Vec<serde_json::Value>>(),
+        })
+    }
+
+    // the share text's header over the boards
+    fn image(&self) -> String {
+        let share_text = self.share_text();
+        image::svg(
+            share_text.lines().next().unwrap_or_default(),
+            &self
+                .boards
+                .iter()
+                .map(|board| &board.game)
+                .collect::<Vec<&Game>>(),
+            &self.config.colors,
+            !self.config.streamer.enabled,
+        )
     }
 
-    fn color_from_known_information(&self, input: &str) -> Line {
-        let span_chars = input
-            .char_indices()
-            .map(|(input_index, input_char)| {
-                if self.bad_characters.contains(&input_char) {
-                    return (input_char, Some(LetterPosition::None));
+    // Wordle 1,234 4/6*
+    fn share_text(&self) -> String {
+        let won = self
+            .boards
+            .iter()
+            .all(|board| board.game.status() == GameStatus::Won);
+        let score = if won {
+            self.guesses_used().to_string()
+        } else {
+            String::from("X")
+        };
+
+        let mut header = String::from("Wordle");
+        match self.mode {
+            GameMode::Practice => {
+                header.push_str(" practice");
+                if self.boards.len() > 1 {
+                    header.push_str(" ×");
+                    header.push_str(&self.boards.len().to_string());
+                }
+                // so whoever it's shared with can play the same word
+                if let Some(seed) = self.seed {
+                    header.push_str(" seed ");
+                    header.push_str(&seed.to_string());
                 }
+            }
+            GameMode::Challenge => header.push_str(" challenge"),
+            GameMode::Absurdle => header = String::from("Absurdle"),
+            GameMode::Daily | GameMode::Archive => {
+                if let Some(meta) = &self.meta {
+                    header.push(' ');
+                    header.push_str(&format_thousands(meta.number));
+                }
+                if self.mode == GameMode::Archive {
+                    header.push_str(" archive");
+                }
+            }
+        }
 
-                (
-                    input_char,
-                    self.known_positions.get(&(input_char, input_index).into()).copied()
-                )
-            })
-            .map(|(input_char, input_position)| {
-                let color = input_position.map_or(Color::White, LetterPosition::color);
-                Span::from(input_char.to_string()).style(Style::default().fg(color))
-            })
-            .collect::<Vec<Span>>();
+        // hard mode results are marked like the official game does, speed runs add their time
+        format!(
+            "{header} {score}/{}{}{}{}{}{}\n\n{}",
+            self.board().game.max_guesses(),
+            if self.hard_mode() { "*" } else { "" },
+            self.config
+                .blitz
+                .limit()
+                .map_or_else(String::new, |limit| format!(" ⚡{}s", limit.as_secs())),
+            if self.speedrun {
+                format!(" ⏱ {}", timer::format(self.timer.elapsed()))
+            } else {
+                String::new()
+            },
+            if self.assist.used() {
+                " (assisted)"
+            } else {
+                ""
+            },
+            match self.board().hints.len() {
+                0 => String::new(),
+                1 => String::from(" (1 hint)"),
+                hints => format!(" ({hints} hints)"),
+            },
+            self.share_grid()
+        ) + &if self.config.share_time {
+            format!("\n\n⏱ {}", timer::format(self.timer.elapsed()))
+        } else {
+            String::new()
+        }
+    }
+
+    // "wordle 3/6", or "wordle X/6" once it's lost
+    fn update_terminal_title(&mut self) {
+        if !self.config.terminal_title {
+            return;
+        }
+
+        let name = match self.mode {
+            GameMode::Daily => "wordle",
+            GameMode::Practice => "wordle practice",
+            GameMode::Challenge => "wordle challenge",
+            GameMode::Absurdle => "absurdle",
+            GameMode::Archive => "wordle archive",
+        };
+        let lost = self
+            .boards
+            .iter()
+            .any(|board| board.game.status() == GameStatus::Lost);
+        let title = format!(
+            "{name} {}/{}",
+            if lost {
+                String::from("X")
+            } else {
+                self.guesses_used().to_string()
+            },
+            self.board().game.max_guesses()
+        );
+
+        if title != self.terminal_title {
+            notify::set_title(&title);
+            self.terminal_title = title;
+        }
+    }
+
+    // Wordle #1,234 — 2025-06-01 (hard)
+    fn title(&self) -> String {
+        let mut title = String::from("Wordle");
+        match self.mode {
+            GameMode::Practice => {
+                title.push_str(" practice");
+                if self.boards.len() > 1 {
+                    title.push_str(" ×");
+                    title.push_str(&self.boards.len().to_string());
+                }
+                if let Some(seed) = self.seed {
+                    title.push_str(" — seed ");
+                    title.push_str(&seed.to_string());
+                }
+            }
+            GameMode::Challenge => title.push_str(" challenge"),
+            GameMode::Absurdle => title = String::from("Absurdle"),
+            GameMode::Daily | GameMode::Archive => {
+                if let Some(meta) = &self.meta {
+                    title.push_str(" #");
+                    title.push_str(&format_thousands(meta.number));
+                }
+                title.push_str(" — ");
+                title.push_str(&self.date.format("%Y-%m-%d").to_string());
+                if self.mode == GameMode::Archive {
+                    title.push_str(" (archive)");
+                }
+            }
+        }
+        if self.hard_mode() {
+            title.push_str(" (hard)");
+        }
 
-        Line::from(span_chars)
+        title
     }
 
     fn draw(&self, frame: &mut Frame) {
+        // nothing lines up below this, better to say so than draw something broken
+        let area = frame.area();
+        if area.width < MIN_WIDTH || area.height < MIN_HEIGHT {
+            self.keyboard_area.set(None);
+            let warning = Paragraph::new(vec![
+                Line::from("terminal too small"),
+                Line::from(format!(
+                    "needs {MIN_WIDTH}×{MIN_HEIGHT}, this is {}×{}",
+                    area.width, area.height
+                ))
+                .dark_gray(),
+            ])
+            .centered()
+            .wrap(Wrap { trim: true });
+            let [warning_area] = Layout::vertical([Constraint::Length(2)])
+                .flex(Flex::Center)
+                .areas(area);
+            frame.render_widget(warning, warning_area);
+            return;
+        }
+
+        self.draw_game(frame);
+
+        match self.popup {
+            Some(Popup::Help) => {
+                self.scroll
+                    .set(help::render(&self.config, frame, self.scroll.get()));
+            }
+            Some(Popup::Stats(tab)) => {
+                self.scroll.set(stats::render(
+                    &self.stats,
+                    &self.config,
+                    frame,
+                    self.date,
+                    tab,
+                    self.scroll.get(),
+                ));
+            }
+            Some(Popup::Analysis) => {
+                if let Some(analysis) = &self.analysis {
+                    analysis::render(analysis, &self.config, frame);
+                }
+            }
+            Some(Popup::Achievements) => {
+                self.scroll.set(achievements::render(
+                    &self.achievements,
+                    &self.config,
+                    frame,
+                    self.scroll.get(),
+                ));
+            }
+            Some(Popup::Leaderboard) => {
+                sync::render(
+                    &self.leaderboard,
+                    &self.config.sync.name(),
+                    &self.config,
+                    frame,
+                );
+            }
+            Some(Popup::Settings) => settings::render(
+                &self.settings,
+                &self.config,
+                self.config_path.as_deref(),
+                frame,
+            ),
+            Some(Popup::ConfirmQuit) => {
+                help::render_quit(&self.config, self.mode == GameMode::Daily, frame);
+            }
+            Some(Popup::History) | None => {}
+        }
+    }
+
+    // the last guess on every board spelled out for screen readers, once it's been revealed
+    fn descriptions(&self) -> Vec<String> {
+        if !self.config.accessible || self.animations.is_revealing() {
+            return Vec::new();
+        }
+
+        self.boards
+            .iter()
+            .enumerate()
+            .filter_map(|(index, board)| {
+                let description = board::describe(board.game.guesses().last()?);
+                Some(if self.boards.len() > 1 {
+                    format!("board {}: {description}", index + 1)
+                } else {
+                    description
+                })
+            })
+            .collect()
+    }
+
+    // the title, who edited the puzzle and what hints have given away
+    fn title_lines(&self) -> Vec<Line<'static>> {
+        let mut title = vec![Line::from(self.title())];
+        if let Some(editor) = self.meta.as_ref().and_then(|meta| meta.editor.as_ref()) {
+            title.push(Line::from(format!("edited by {editor}")).dark_gray());
+        }
+        if !self.board().hints.is_empty() && self.view == View::Playing {
+            let board = self.board();
+            title.push(hints::summary(
+                &board.hints,
+                board.game.word_length(),
+                self.config.hints.saturating_sub(board.hints.len()),
+            ));
+        }
+        title
+    }
+
+    fn draw_game(&self, frame: &mut Frame) {
+        // multi-board games have a keyboard under every board instead
+        let keyboard = self.view == View::Playing && self.boards.len() == 1;
+        let descriptions = self.descriptions();
+        #[allow(clippy::cast_possible_truncation)]
         let layout = Layout::default()
             .direction(ratatui::layout::Direction::Vertical)
             .constraints([
                 Constraint::Length(3),
                 Constraint::Min(1),
-                Constraint::Length(3),
+                Constraint::Length(descriptions.len() as u16),
+                // why the last guess was rejected, when it was
+                Constraint::Length(1),
+                Constraint::Length(match self.view {
+                    View::Playing if keyboard => 2,
+                    View::Playing => 3,
+                    View::Results { .. } => 12,
+                }),
+                Constraint::Length(if keyboard { keyboard::HEIGHT } else { 0 }),
             ])
             .split(frame.area());
 
-        let title = Paragraph::new("wordle")
-            .style(Style::default().fg(Color::LightBlue).dim())
+        let title = Paragraph::new(self.title_lines())
+            .style(Style::default().fg(self.config.colors.title).dim())
             .centered();
         frame.render_widget(title, layout[0]);
 
-        let guesses: Vec<ListItem> = self
-            .guesses
+        if self.config.timer {
+            let timer = Paragraph::new(timer::format(self.timer.elapsed()))
+                .style(Style::default().fg(Color::DarkGray))
+                .right_aligned();
+            frame.render_widget(timer, layout[0]);
+        }
+        if let Some(remaining) = self.deadline.as_ref().and_then(Deadline::remaining) {
+            // rounded up so it's never showing 0 with time still left
+            let seconds = remaining.as_millis().div_ceil(1000);
+            let color = if seconds <= 5 {
+                Color::LightRed
+            } else {
+                Color::DarkGray
+            };
+            frame.render_widget(
+                Paragraph::new(format!("⚡ {seconds}s")).style(Style::default().fg(color)),
+                layout[0],
+            );
+        }
+
+        if self.boards.len() > 1 {
+            self.draw_boards(frame, layout[1]);
+        } else {
+            let mut guesses_area = layout[1];
+
+            // the assist panel only makes sense while there's still something to guess
+            if let (Assist::Visible(panel), View::Playing) = (self.assist, self.view) {
+                let [board_area, assist_area] =
+                    Layout::horizontal([Constraint::Min(1), Constraint::Length(assist::WIDTH)])
+                        .areas(layout[1]);
+
+                guesses_area = board_area;
+                let candidates = self.board().candidates(self.possible_answers());
+                let word_length = self.board().game.word_length();
+                assist::render(panel, &candidates, word_length, frame, assist_area);
+            }
+
+            self.board().render_tiles(
+                frame,
+                guesses_area,
+                &self.config.colors,
+                self.config.double_letters,
+                |row| self.animations.revealed_tiles(row),
+            );
+        }
+
+        let descriptions = Paragraph::new(descriptions.join("\n")).centered();
+        frame.render_widget(descriptions, layout[2]);
+
+        if let Some((message, kind)) = self.messages.current() {
+            let color = match kind {
+                messages::Kind::Error => Color::LightRed,
+                messages::Kind::Notice => self.config.colors.correct,
+            };
+            let message = Paragraph::new(message)
+                .style(Style::default().fg(color))
+                .centered();
+            frame.render_widget(message, layout[3]);
+        }
+
+        self.keyboard_area.set(None);
+        if let View::Results {
+            copied,
+            already_played,
+        } = self.view
+        {
+            if !self.animations.is_revealing() {
+                self.draw_results(frame, layout[4], copied, already_played);
+            }
+            return;
+        }
+
+        self.draw_input(frame, layout[4]);
+
+        if keyboard {
+            let lines = keyboard::full(
+                self.board(),
+                &self.config.colors,
+                self.config.double_letters,
+                self.config.keyboard_layout,
+            );
+            frame.render_widget(Paragraph::new(lines), layout[5]);
+            self.keyboard_area.set(Some(layout[5]));
+        }
+    }
+
+    // what's been typed so far, and the mode it's being typed in with `keys.modal`
+    fn draw_input(&self, frame: &mut Frame, area: Rect) {
+        // the letters mean something different on every board, so they're left uncolored
+        let input_line = if self.config.streamer.enabled {
+            // nothing for chat to backseat until it's submitted
+            Line::from("•".repeat(self.input.chars().count()))
+        } else if self.boards.len() > 1 {
+            Line::from(self.input.as_str())
+        } else {
+            self.board().input_line(&self.input, &self.config.colors)
+        };
+        let mut input_line = self.animations.shake_line(input_line);
+        input_line
+            .spans
+            .extend(self.autocomplete.ghost(&self.input, &self.completions()));
+        let input = Paragraph::new(input_line).centered();
+        frame.render_widget(input, area);
+        if self.config.keys.modal {
+            let mode = match self.input_mode {
+                InputMode::Insert => "-- insert --",
+                InputMode::Normal => "-- normal --",
+            };
+            frame.render_widget(
+                Paragraph::new(mode)
+                    .style(Style::default().fg(Color::DarkGray))
+                    .right_aligned(),
+                area,
+            );
+        }
+    }
+
+    // as square a grid as the board count allows, each with its own keyboard underneath
+    fn draw_boards(&self, frame: &mut Frame, area: Rect) {
+        let count = self.boards.len();
+        let mut columns = count.isqrt();
+        if columns * columns < count {
+            columns += 1;
+        }
+        let rows = count.div_ceil(columns);
+
+        #[allow(clippy::cast_possible_truncation)]
+        let row_areas = Layout::vertical(vec![Constraint::Ratio(1, rows as u32); rows]).split(area);
+        for (row, boards) in self.boards.chunks(columns).enumerate() {
+            #[allow(clippy::cast_possible_truncation)]
+            let cell_areas =
+                Layout::horizontal(vec![Constraint::Ratio(1, columns as u32); columns])
+                    .split(row_areas[row]);
+
+            for (column, board) in boards.iter().enumerate() {
+                self.draw_board(frame, cell_areas[column], row * columns + column, board);
+            }
+        }
+    }
+
+    fn draw_board(&self, frame: &mut Frame, area: Rect, index: usize, board: &Board) {
+        let border_color = match board.game.status() {
+            GameStatus::InProgress => Color::DarkGray,
+            GameStatus::Won => self.config.colors.correct,
+            GameStatus::Lost => Color::LightRed,
+        };
+        let block = Block::bordered()
+            .title(Line::from(format!(" {} ", index + 1)).centered())
+            .border_style(Style::default().fg(border_color));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let [guesses_area, keyboard_area] =
+            Layout::vertical([Constraint::Min(1), Constraint::Length(keyboard::HEIGHT)])
+                .areas(inner);
+
+        frame.render_widget(
+            board.guesses_list(
+                &self.config.colors,
+                |row| self.animations.revealed_tiles(row),
+                false,
+            ),
+            guesses_area,
+        );
+        frame.render_widget(
+            Paragraph::new(keyboard::compact(
+                board,
+                &self.config.colors,
+                self.config.keyboard_layout,
+            )),
+            keyboard_area,
+        );
+    }
+
+    fn draw_results(&self, frame: &mut Frame, area: Rect, copied: bool, already_played: bool) {
+        let [banner_area, summary_area] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(1)]).areas(area);
+
+        let missed = self
+            .boards
             .iter()
-            .map(|letters| {
-                let colored_spans = letters
-                    .iter()
-                    .map(|(c, p)| {
-                        Span::from(c.to_string())
-                            .style(Style::default().fg(p.unwrap_or(LetterPosition::None).color()))
-                    })
-                    .collect::<Vec<Span>>();
+            .filter(|board| board.game.status() != GameStatus::Won)
+            .map(|board| board.game.solution())
+            .collect::<Vec<&str>>();
+        let mut banner = vec![if missed.is_empty() {
+            Line::from(format!(
+                "solved in {}/{}",
+                self.guesses_used(),
+                self.board().game.max_guesses()
+            ))
+            .style(Style::default().fg(self.config.colors.correct).bold())
+        } else {
+            Line::from(vec![
+                Span::from(if self.boards.len() > 1 {
+                    "missed "
+                } else {
+                    "the word was "
+                }),
+                Span::from(missed.join(", ")).bold(),
+            ])
+            .style(Style::default().fg(Color::LightRed))
+        }];
+        match &self.definition {
+            Definition::Off => {}
+            Definition::Looking(_) => banner.push(Line::from("looking it up...").dark_gray()),
+            Definition::Found(definition) => banner.push(
+                Line::from(truncate(definition, banner_area.width.into()))
+                    .italic()
+                    .dark_gray(),
+            ),
+            Definition::Missing(why) => banner.push(Line::from(why.as_str()).dark_gray().dim()),
+        }
+        // there's no definition to make room for, it's only looked up as the game finishes
+        if already_played {
+            banner.push(Line::from("you already played today").bold());
+        }
+        if self.mode == GameMode::Daily {
+            banner.push(Line::from(countdown::message(&self.config)).dark_gray());
+        }
+        frame.render_widget(Paragraph::new(banner).centered(), banner_area);
 
-                ListItem::new(Line::from(colored_spans).centered())
-            })
-            .collect();
+        let keys = &self.config.keys;
+        let mut hint = Vec::new();
+        match self.mode {
+            GameMode::Practice | GameMode::Absurdle => {
+                hint.push(format!("{} for a new word", keys.new_round.primary()));
+            }
+            _ if copied => hint.push(format!("copied, {} to copy again", keys.copy.primary())),
+            _ => {}
+        }
+        if !copied || self.mode.is_random() {
+            hint.push(format!("{} to copy results", keys.copy.primary()));
+        }
+        if self.mode == GameMode::Daily {
+            hint.push(format!("{} for stats", keys.stats.primary()));
+        }
+        if self.boards.len() == 1 {
+            hint.push(format!("{} for analysis", keys.analysis.primary()));
+        }
+        hint.push(format!("{} for achievements", keys.achievements.primary()));
+        if self.mode == GameMode::Daily && self.config.sync.url.is_some() {
+            hint.push(format!(
+                "{} for the leaderboard",
+                keys.leaderboard.primary()
+            ));
+        }
+        hint.push(format!("{} to quit", keys.close.primary()));
+        let hint = if self.webhook.is_asking() {
+            String::from("post your result to the webhook? y to post, n to skip")
+        } else {
+            hint.join(", ")
+        };
+
+        // only daily games are part of the stats, so there's nothing to summarize otherwise
+        if self.mode != GameMode::Daily {
+            let hint = Paragraph::new(hint)
+                .style(Style::default().fg(Color::DarkGray).dim())
+                .centered();
+            frame.render_widget(hint, summary_area);
+            return;
+        }
+
+        stats::render_summary(&self.stats, frame, summary_area, self.date, &hint);
+    }
+}
 
-        let guesses_list = List::new(guesses)
-            .style(Style::default().fg(Color::White))
-            .highlight_style(Style::default().fg(Color::Yellow))
-            .highlight_symbol(">");
+// cut down to `width` characters, with an ellipsis if anything was cut
+fn truncate(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_owned();
+    }
 
-        frame.render_widget(guesses_list, layout[1]);
+    let mut truncated = text
+        .chars()
+        .take(width.saturating_sub(1))
+        .collect::<String>();
+    truncated.push('…');
+    truncated
+}
 
-        let input = Paragraph::new(self.color_from_known_information(&self.current_guess_input))
-            .centered();
-        frame.render_widget(input, layout[2]);
+fn format_thousands(n: u64) -> String {
+    let digits = n.to_string();
+    let mut formatted = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            formatted.push(',');
+        }
+        formatted.push(c);
     }
+
+    formatted
 }
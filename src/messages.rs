@@ -0,0 +1,65 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+const MESSAGE_DURATION: Duration = Duration::from_millis(1500);
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Kind {
+    // something went wrong, like a rejected guess
+    Error,
+    // good news, like an unlocked achievement
+    Notice,
+}
+
+// short notices shown one after another, each for a moment before the next takes its place
+#[derive(Debug, Default)]
+pub struct Messages {
+    queue: VecDeque<(String, Kind)>,
+    // when the message at the front of the queue first showed up
+    shown_at: Option<Instant>,
+}
+
+impl Messages {
+    pub fn push(&mut self, message: impl Into<String>) {
+        self.push_kind(message.into(), Kind::Error);
+    }
+
+    pub fn notify(&mut self, message: impl Into<String>) {
+        self.push_kind(message.into(), Kind::Notice);
+    }
+
+    fn push_kind(&mut self, message: String, kind: Kind) {
+        let message = (message, kind);
+        // mashing enter keeps the same complaint up instead of queueing copies of it
+        if self.queue.back() == Some(&message) {
+            if self.queue.len() == 1 {
+                self.shown_at = Some(Instant::now());
+            }
+            return;
+        }
+
+        if self.queue.is_empty() {
+            self.shown_at = Some(Instant::now());
+        }
+        self.queue.push_back(message);
+    }
+
+    // drops whatever has been on screen long enough, called once per redraw
+    pub fn tick(&mut self) {
+        if self
+            .shown_at
+            .is_some_and(|shown_at| shown_at.elapsed() >= MESSAGE_DURATION)
+        {
+            let _ = self.queue.pop_front();
+            self.shown_at = (!self.queue.is_empty()).then(Instant::now);
+        }
+    }
+
+    pub fn current(&self) -> Option<(&str, Kind)> {
+        self.queue
+            .front()
+            .map(|(message, kind)| (message.as_str(), *kind))
+    }
+}
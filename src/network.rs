@@ -0,0 +1,286 @@
+use anyhow::{anyhow, ensure, Context, Result};
+use rustls::{ClientConfig, RootCertStore};
+use rustls_pki_types::{pem::PemObject, CertificateDer};
+use serde_json::Value;
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    fs,
+    path::Path,
+    sync::Arc,
+    thread,
+    time::Duration,
+};
+use tracing::{debug, debug_span, warn};
+use ureq::{Agent, AgentBuilder, Proxy, Request, Response};
+
+use crate::config::Config;
+
+// how many more times a busy server is asked before giving up
+const RETRIES: u32 = 3;
+const FIRST_BACKOFF: Duration = Duration::from_secs(1);
+// a server that wants longer than this is treated as down for now
+const MAX_RETRY_AFTER: Duration = Duration::from_mins(1);
+
+// shared by every request, so the daily puzzle and the word list both go through whatever
+// proxy and certificates are set up
+pub fn agent(config: &Config) -> Result<Agent> {
+    let network = &config.network;
+    // without a proxy in the config, HTTPS_PROXY, HTTP_PROXY and ALL_PROXY are used if set
+    let mut builder = AgentBuilder::new()
+        .timeout(config.timeouts.request())
+        .try_proxy_from_env(true);
+
+    if let Some(proxy) = &network.proxy {
+        builder =
+            builder.proxy(Proxy::new(proxy).with_context(|| format!("invalid proxy {proxy}"))?);
+    }
+    if let Some(path) = &network.ca_certificates {
+        builder = builder.tls_config(Arc::new(tls_config(path)?));
+    }
+
+    Ok(builder.build())
+}
+
+// the usual roots plus everything in `path`, for proxies that re-sign traffic with a
+// certificate of their own
+fn tls_config(path: &Path) -> Result<ClientConfig> {
+    let pem = fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let certificates = CertificateDer::pem_slice_iter(&pem)
+        .collect::<Result<Vec<CertificateDer>, _>>()
+        .map_err(|err| anyhow!("failed to parse {}: {err:?}", path.display()))?;
+    ensure!(
+        !certificates.is_empty(),
+        "no certificates in {}",
+        path.display()
+    );
+
+    let mut roots = RootCertStore {
+        roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+    };
+    for certificate in certificates {
+        roots
+            .add(certificate)
+            .with_context(|| format!("invalid certificate in {}", path.display()))?;
+    }
+
+    Ok(
+        ClientConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+            .with_safe_default_protocol_versions()?
+            .with_root_certificates(roots)
+            .with_no_client_auth(),
+    )
+}
+
+// why a request didn't work out, worded for the loading screen and the messages under the board
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum HttpError {
+    // the server answered with something other than what was asked for
+    Status {
+        url: String,
+        code: u16,
+    },
+    // still told to slow down after backing off, with how long it asked for if it said
+    RateLimited {
+        url: String,
+        retry_after: Option<Duration>,
+    },
+    // never got an answer at all, like no connection or a timeout
+    Transport {
+        url: String,
+        message: String,
+    },
+}
+
+impl HttpError {
+    // whether asking again later could go any differently, a busy server or a request that
+    // never got through. anything else would only be answered the same way
+    pub const fn is_transient(&self) -> bool {
+        match self {
+            Self::Status { code, .. } => *code >= 500,
+            Self::RateLimited { .. } | Self::Transport { .. } => true,
+        }
+    }
+
+    pub const fn code(&self) -> Option<u16> {
+        match self {
+            Self::Status { code, .. } => Some(*code),
+            Self::RateLimited { .. } => Some(429),
+            Self::Transport { .. } => None,
+        }
+    }
+
+    fn from_ureq(url: &str, err: ureq::Error) -> Self {
+        match err {
+            ureq::Error::Status(429, response) => Self::RateLimited {
+                url: url.to_owned(),
+                retry_after: retry_after(&response),
+            },
+            ureq::Error::Status(code, _) => Self::Status {
+                url: url.to_owned(),
+                code,
+            },
+            ureq::Error::Transport(transport) => Self::Transport {
+                url: url.to_owned(),
+                message: transport.message().map_or_else(
+                    || transport.kind().to_string(),
+                    |message| format!("{}: {message}", transport.kind()),
+                ),
+            },
+        }
+    }
+}
+
+// just the host, the rest of a url is mostly noise in an error message
+fn host(url: &str) -> &str {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    rest.split(['/', '?', '#']).next().unwrap_or(rest)
+}
+
+impl Display for HttpError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Status { url, code } if *code >= 500 => {
+                write!(f, "{} is having trouble (error {code})", host(url))
+            }
+            Self::Status { url, code } => write!(f, "{} answered with error {code}", host(url)),
+            Self::RateLimited {
+                url,
+                retry_after: Some(retry_after),
+            } => write!(
+                f,
+                "{} is getting too many requests, try again in {}s",
+                host(url),
+                retry_after.as_secs()
+            ),
+            Self::RateLimited { url, .. } => write!(
+                f,
+                "{} is getting too many requests, try again later",
+                host(url)
+            ),
+            Self::Transport { url, message } => {
+                write!(f, "couldn't reach {}: {message}", host(url))
+            }
+        }
+    }
+}
+
+impl Error for HttpError {}
+
+// whether asking again could help, a response that couldn't be read won't read any better
+// the second time
+pub fn is_transient(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<HttpError>()
+        .is_some_and(HttpError::is_transient)
+}
+
+// only the seconds form, nobody sends the date one
+fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .header("Retry-After")
+        .and_then(|seconds| seconds.trim().parse().ok())
+        .map(Duration::from_secs)
+}
+
+// every request goes through here. a server that's busy (5xx) or asking to slow down (429) is
+// asked again after waiting twice as long each time, or as long as it asked for
+fn send(
+    url: &str,
+    send: impl Fn() -> Result<Response, Box<ureq::Error>>,
+) -> Result<Response, HttpError> {
+    let _request = debug_span!("request", %url).entered();
+    let mut backoff = FIRST_BACKOFF;
+    let mut retries = 0;
+    loop {
+        debug!("sent");
+        let (code, response) = match send().map_err(|err| *err) {
+            Err(ureq::Error::Status(code, response)) if code == 429 || code >= 500 => {
+                (code, response)
+            }
+            Ok(response) => {
+                debug!(status = response.status(), "answered");
+                return Ok(response);
+            }
+            Err(err) => {
+                let err = HttpError::from_ureq(url, err);
+                warn!("{err}");
+                return Err(err);
+            }
+        };
+
+        let wait = retry_after(&response).unwrap_or(backoff);
+        if retries == RETRIES || wait > MAX_RETRY_AFTER {
+            let err = HttpError::from_ureq(url, ureq::Error::Status(code, response));
+            warn!("{err}");
+            return Err(err);
+        }
+        warn!("answered {code}, asking again in {wait:?}");
+        thread::sleep(wait);
+        backoff *= 2;
+        retries += 1;
+    }
+}
+
+pub fn call(request: &Request) -> Result<Response, HttpError> {
+    send(request.url(), || request.clone().call().map_err(Box::new))
+}
+
+pub fn send_json(request: &Request, body: &Value) -> Result<Response, HttpError> {
+    send(request.url(), || {
+        request.clone().send_json(body.clone()).map_err(Box::new)
+    })
+}
+
+pub fn get_string(agent: &Agent, url: &str) -> Result<String, HttpError> {
+    read_string(url, call(&agent.get(url))?)
+}
+
+fn read_string(url: &str, response: Response) -> Result<String, HttpError> {
+    response.into_string().map_err(|err| HttpError::Transport {
+        url: url.to_owned(),
+        message: err.to_string(),
+    })
+}
+
+// what a cached download was last sent with, so it's only sent again if it changed
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Validators {
+    pub url: String,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum Fetched {
+    Changed(String, Validators),
+    Unchanged,
+}
+
+// a download that's skipped when `cached` says it's still the same, `cached` only counts if
+// it's for this url
+pub fn get_if_changed(
+    agent: &Agent,
+    url: &str,
+    cached: Option<&Validators>,
+) -> Result<Fetched, HttpError> {
+    let mut request = agent.get(url);
+    if let Some(cached) = cached.filter(|cached| cached.url == url) {
+        if let Some(etag) = &cached.etag {
+            request = request.set("If-None-Match", etag);
+        }
+        if let Some(last_modified) = &cached.last_modified {
+            request = request.set("If-Modified-Since", last_modified);
+        }
+    }
+
+    let response = call(&request)?;
+    if response.status() == 304 {
+        return Ok(Fetched::Unchanged);
+    }
+    let validators = Validators {
+        url: url.to_owned(),
+        etag: response.header("ETag").map(str::to_owned),
+        last_modified: response.header("Last-Modified").map(str::to_owned),
+    };
+    Ok(Fetched::Changed(read_string(url, response)?, validators))
+}
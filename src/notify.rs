@@ -0,0 +1,69 @@
+use crossterm::{execute, terminal::SetTitle};
+use serde::{Deserialize, Serialize};
+use std::{
+    io::{self, Write},
+    process::{Command, Stdio},
+    thread,
+};
+
+// what the terminal's tab or window is called while the game has it, like "wordle 3/6"
+pub fn set_title(title: &str) {
+    let _ = execute!(io::stdout(), SetTitle(title));
+}
+
+// xterm's title stack, so whatever the shell had set comes back afterwards. terminals without
+// one ignore these
+pub fn save_title() {
+    let _ = write!(io::stdout(), "\x1b[22;0t");
+}
+
+pub fn restore_title() {
+    let _ = write!(io::stdout(), "\x1b[23;0t");
+}
+
+// how to get someone's attention when the game isn't the window they're looking at
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Notify {
+    #[default]
+    Off,
+    // OSC 9, which the terminal turns into a notification itself, also over ssh
+    Terminal,
+    // notify-send on linux and the bsds, osascript on macos
+    System,
+}
+
+impl Notify {
+    // nothing is said if it couldn't be sent, it's not worth interrupting the game over
+    pub fn send(self, title: &str, body: &str) {
+        match self {
+            Self::Off => {}
+            Self::Terminal => {
+                let mut stdout = io::stdout();
+                let _ = write!(stdout, "\x1b]9;{title}: {body}\x07").and_then(|()| stdout.flush());
+            }
+            Self::System => {
+                let mut command = if cfg!(target_os = "macos") {
+                    let mut command = Command::new("osascript");
+                    command.arg("-e").arg(format!(
+                        "display notification {body:?} with title {title:?}"
+                    ));
+                    command
+                } else {
+                    let mut command = Command::new("notify-send");
+                    command.arg(title).arg(body);
+                    command
+                };
+                // anything it prints would end up drawn over the game
+                let child = command
+                    .stdin(Stdio::null())
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::null())
+                    .spawn();
+                if let Ok(mut child) = child {
+                    thread::spawn(move || child.wait());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use chrono::{Datelike, NaiveDate};
+use std::collections::HashSet;
+
+// bundled so the game still works without network access
+const WORD_LIST: &str = include_str!("words.txt");
+
+pub fn word_list() -> HashSet<String> {
+    WORD_LIST.lines().map(str::to_uppercase).collect()
+}
+
+// the same date always picks the same word so offline games are still a "daily" puzzle
+pub fn solution_for(date: NaiveDate, word_list: &HashSet<String>) -> Option<String> {
+    if word_list.is_empty() {
+        return None;
+    }
+
+    let mut words = word_list.iter().collect::<Vec<&String>>();
+    words.sort_unstable();
+
+    // splitmix64, so neighbouring days don't land on neighbouring words
+    let mut x =
+        u64::from(date.num_days_from_ce().unsigned_abs()).wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^= x >> 31;
+
+    #[allow(clippy::cast_possible_truncation)]
+    Some(words[(x % words.len() as u64) as usize].clone())
+}
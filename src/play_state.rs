@@ -0,0 +1,205 @@
+use anyhow::Result;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::{iter, path::Path, time::Duration};
+
+use crate::storage::{self, Contents};
+
+// bumped whenever a field changes meaning, new fields just need a default
+const VERSION: u32 = 1;
+
+// the daily puzzle as it was left, so it can be resumed or refused once it's finished
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct PlayState {
+    // see `hash_solution`, so reading the file doesn't spoil the answer for anyone else
+    pub solution_hash: String,
+    // the day it was played for, older versions didn't keep it
+    pub date: Option<NaiveDate>,
+    pub hard_mode: bool,
+    // time spent playing so far
+    #[serde(rename = "elapsed_secs", with = "storage::seconds")]
+    pub elapsed: Duration,
+    // whether the assist panel was opened at any point
+    pub assisted: bool,
+    // see `hints::encode`. these and the guesses are written obscured, a revealed letter or a
+    // finished game's last guess would give the answer away
+    #[serde(with = "obscured")]
+    pub hints: Vec<String>,
+    #[serde(with = "obscured")]
+    pub guesses: Vec<String>,
+    // how long each guess took, see `GameRecord::think_times`
+    pub think_secs: Vec<u64>,
+    // only present once the game is over
+    pub share: Option<String>,
+}
+
+impl PlayState {
+    pub fn is_for(&self, solution: &str) -> bool {
+        match self.solution_hash.split_once(':') {
+            Some((salt, _)) => u64::from_str_radix(salt, 16)
+                .is_ok_and(|salt| self.solution_hash == salted_hash(salt, solution)),
+            // older versions stored the solution as is
+            None => self.solution_hash.eq_ignore_ascii_case(solution),
+        }
+    }
+
+    pub fn think_times(&self) -> Vec<Duration> {
+        self.think_secs
+            .iter()
+            .copied()
+            .map(Duration::from_secs)
+            .collect()
+    }
+
+    pub fn load(path: &Path) -> Option<Self> {
+        match storage::read_versioned(path) {
+            Contents::Versioned(state) => serde_json::from_value(state).ok(),
+            Contents::Legacy(contents) => Self::parse_legacy(&contents),
+            Contents::Missing => None,
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        storage::write_versioned(path, VERSION, self)
+    }
+
+    // what versions before the json format wrote:
+    // <salt>:<solution hash>
+    // <in-progress|finished> <hard|normal> <seconds played> [assisted]
+    // <guesses separated by spaces>
+    // <share text, if finished>
+    fn parse_legacy(contents: &str) -> Option<Self> {
+        let mut lines = contents.lines();
+        let solution_hash = lines.next()?.to_owned();
+
+        let status_line = lines.next().unwrap_or_default();
+        let mut status = status_line.split_whitespace();
+        let finished = match status.next() {
+            Some("in-progress") => false,
+            Some("finished") => true,
+            // older versions only stored the solution followed by the share text
+            _ => {
+                let share = iter::once(status_line)
+                    .chain(lines)
+                    .collect::<Vec<&str>>()
+                    .join("\n");
+
+                return Some(Self {
+                    solution_hash,
+                    date: None,
+                    hard_mode: false,
+                    elapsed: Duration::ZERO,
+                    assisted: false,
+                    hints: Vec::new(),
+                    guesses: Vec::new(),
+                    think_secs: Vec::new(),
+                    share: Some(share),
+                });
+            }
+        };
+        let hard_mode = status.next() == Some("hard");
+        let elapsed = Duration::from_secs(status.next().and_then(|s| s.parse().ok()).unwrap_or(0));
+
+        let guesses = lines
+            .next()
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(ToString::to_string)
+            .collect();
+
+        let share = finished.then(|| lines.collect::<Vec<&str>>().join("\n"));
+
+        let assisted = status.next() == Some("assisted");
+
+        Some(Self {
+            solution_hash,
+            date: None,
+            hard_mode,
+            elapsed,
+            assisted,
+            hints: Vec::new(),
+            guesses,
+            think_secs: Vec::new(),
+            share,
+        })
+    }
+}
+
+// each one through `challenge::obscure` with a ~ in front, older versions wrote them as they were
+mod obscured {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    use crate::challenge;
+
+    pub fn serialize<S: Serializer>(fields: &[String], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            fields
+                .iter()
+                .map(|field| format!("~{}", challenge::obscure(field))),
+        )
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<String>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?
+            .into_iter()
+            .map(|field| {
+                field
+                    .strip_prefix('~')
+                    .map_or_else(|| Some(field.clone()), challenge::unobscure)
+            })
+            .collect::<Option<Vec<String>>>()
+            .ok_or_else(|| D::Error::custom("couldn't unobscure the play state"))
+    }
+}
+
+// only meant to keep the answer from being read at a glance, a five letter word is trivial to
+// brute force no matter how it's hashed
+pub fn hash_solution(solution: &str) -> String {
+    salted_hash(fastrand::u64(..), solution)
+}
+
+fn salted_hash(salt: u64, solution: &str) -> String {
+    let hash = storage::hash(
+        salt.to_le_bytes()
+            .into_iter()
+            .chain(solution.to_uppercase().into_bytes()),
+    );
+
+    format!("{salt:016x}:{hash:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_finished_game_is_saved_without_its_answer_in_sight() {
+        let path =
+            std::env::temp_dir().join(format!("wordle-tui-play-state-{}", std::process::id()));
+        let state = PlayState {
+            solution_hash: hash_solution("CRANE"),
+            hints: vec![String::from("0C")],
+            guesses: vec![String::from("SLATE"), String::from("CRANE")],
+            share: Some(String::from("Wordle 2/6")),
+            ..PlayState::default()
+        };
+        state.save(&path).unwrap();
+
+        let saved = std::fs::read_to_string(&path).unwrap();
+        let loaded = PlayState::load(&path);
+        let _ = std::fs::remove_file(&path);
+        assert!(!saved.to_uppercase().contains("CRANE"), "{saved}");
+        assert!(!saved.contains("0C"), "{saved}");
+        assert_eq!(loaded, Some(state));
+    }
+
+    #[test]
+    fn guesses_written_as_they_were_are_still_read() {
+        let state =
+            serde_json::from_str::<PlayState>(r#"{"guesses": ["SLATE", "CRANE"]}"#).unwrap();
+        assert_eq!(state.guesses, ["SLATE", "CRANE"]);
+    }
+}
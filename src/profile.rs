@@ -0,0 +1,190 @@
+use anyhow::{ensure, Result};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Padding, Paragraph},
+    Frame,
+};
+use std::{
+    fs,
+    io::{self, IsTerminal},
+    path::PathBuf,
+    sync::OnceLock,
+};
+
+use crate::{cli::Args, color_support::ColorSupport, config::Colors, help, tui::Tui};
+
+// picked once at startup, before anything works out where its files go
+static PROFILE: OnceLock<String> = OnceLock::new();
+
+// names end up in paths, so nothing that could climb out of the profiles folder
+pub fn validate(name: &str) -> Result<()> {
+    ensure!(
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '-' || c == '_'),
+        "profile names can only have letters, numbers, - and _"
+    );
+    Ok(())
+}
+
+pub fn select(name: String) {
+    // only ever called once, from main
+    let _ = PROFILE.set(name);
+}
+
+pub fn current() -> Option<&'static str> {
+    PROFILE.get().map(String::as_str)
+}
+
+// every profile's config and data go in a folder of its own under here, the default profile
+// keeps using the top level
+pub fn dir(root: PathBuf) -> PathBuf {
+    match current() {
+        Some(name) => root.join("profiles").join(name),
+        None => root,
+    }
+}
+
+// the profiles that have a config folder, in order
+fn list() -> Vec<String> {
+    let Some(root) = dirs::config_dir().map(|dir| dir.join("wordle-tui").join("profiles")) else {
+        return Vec::new();
+    };
+    let mut profiles = fs::read_dir(root)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| validate(name).is_ok())
+        .collect::<Vec<String>>();
+    profiles.sort_unstable();
+    profiles
+}
+
+// once there's more than the default profile, who's playing is asked rather than assumed
+pub fn needs_picking(args: &Args) -> bool {
+    args.profile.is_none()
+        && args.config.is_none()
+        && !args.no_tui
+        && args.bench.is_none()
+        && args.create_challenge.is_none()
+        && io::stdout().is_terminal()
+        && !list().is_empty()
+}
+
+#[derive(Debug)]
+struct Picker {
+    // "default" first, then every profile, then a new one
+    profiles: Vec<String>,
+    selected: usize,
+    new_name: String,
+}
+
+impl Picker {
+    const fn on_new(&self) -> bool {
+        self.selected == self.profiles.len()
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Choice {
+    Default,
+    Profile(String),
+    Quit,
+}
+
+// who's playing, asked before anything else is loaded
+pub fn pick() -> Result<Choice> {
+    let mut picker = Picker {
+        profiles: list(),
+        selected: 0,
+        new_name: String::new(),
+    };
+    picker.profiles.insert(0, String::from("default"));
+
+    let mut terminal = Tui::init(false, ColorSupport::Auto.detect())?;
+    let choice = loop {
+        terminal.draw(|frame| draw(frame, &picker))?;
+
+        let Event::Key(key_event) = event::read()? else {
+            continue;
+        };
+        if key_event.kind != KeyEventKind::Press {
+            continue;
+        }
+        match key_event.code {
+            KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                break Choice::Quit;
+            }
+            KeyCode::Up => picker.selected = picker.selected.saturating_sub(1),
+            KeyCode::Down => picker.selected = (picker.selected + 1).min(picker.profiles.len()),
+            KeyCode::Enter if picker.on_new() && validate(&picker.new_name).is_ok() => {
+                break Choice::Profile(picker.new_name);
+            }
+            KeyCode::Enter if picker.on_new() => {}
+            KeyCode::Enter if picker.selected == 0 => break Choice::Default,
+            KeyCode::Enter => break Choice::Profile(picker.profiles[picker.selected].clone()),
+            KeyCode::Backspace if picker.on_new() => {
+                let _ = picker.new_name.pop();
+            }
+            KeyCode::Char(c) if picker.on_new() => picker.new_name.push(c),
+            _ => {}
+        }
+    };
+    drop(terminal);
+
+    Ok(choice)
+}
+
+fn draw(frame: &mut Frame, picker: &Picker) {
+    let title = Colors::default().title;
+    let mut lines = vec![Line::from("who's playing?").bold(), Line::default()];
+    lines.extend(picker.profiles.iter().enumerate().map(|(i, name)| {
+        if i == picker.selected {
+            Line::from(format!("> {name}")).fg(title).bold()
+        } else {
+            Line::from(format!("  {name}"))
+        }
+    }));
+
+    let new = if picker.on_new() {
+        Line::from(vec![
+            Span::from("> new: "),
+            Span::from(picker.new_name.as_str()).fg(title),
+            Span::from("_").dim(),
+        ])
+        .bold()
+    } else {
+        Line::from("  new profile...").dark_gray()
+    };
+    lines.push(new);
+
+    #[allow(clippy::cast_possible_truncation)]
+    let area = help::centered(frame.area(), help::WIDTH, lines.len() as u16 + 2);
+    let [_, hint_area] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::bordered()
+                .title(" profiles ")
+                .title_bottom(
+                    Line::from(" each has its own stats, saved game and config ").centered(),
+                )
+                .border_style(Style::default().fg(Color::DarkGray))
+                .padding(Padding::horizontal(1)),
+        ),
+        area,
+    );
+    frame.render_widget(
+        Paragraph::new("↑/↓ to choose, enter to play, ctrl+c to quit")
+            .style(Style::default().fg(Color::DarkGray).dim())
+            .centered(),
+        hint_area,
+    );
+}
@@ -0,0 +1,639 @@
+use anyhow::{Context, Result};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Paragraph},
+    Frame,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashSet,
+    sync::mpsc::{self, Receiver, RecvTimeoutError, Sender, TryRecvError},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+use ureq::{Agent, Request};
+use wordle_tui::wordle_core::{Game, GameStatus, LetterState, MAX_GUESSES};
+
+use crate::{
+    animation::Animations,
+    answers,
+    board::{self, Board},
+    config::Config,
+    event_loop::{Crossterm, Event, Events},
+    keymap::Action,
+    loading::Puzzle,
+    network, storage, timer, tui,
+    tui::Terminal,
+};
+
+// how often the room is asked for everyone's progress
+const POLL: Duration = Duration::from_secs(1);
+// how long leaving waits for the last of this player's progress to reach the relay
+const CLOSE_TIMEOUT: Duration = Duration::from_secs(3);
+// how wide each opponent's board is drawn, name included
+const OPPONENT_WIDTH: u16 = 18;
+
+// one player's side of the race as the relay passes it around. a relay is anything that
+// speaks this:
+//   POST <url>/rooms/<room>  your `Player` as json, replacing whatever that id sent before
+//   GET  <url>/rooms/<room>  everyone who's sent anything, as a json list
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Player {
+    // picked fresh each game, names aren't unique enough to tell two players apart
+    pub id: String,
+    pub name: String,
+    // waiting in the lobby for the race to start
+    pub ready: bool,
+    // every guess as a letter per tile, c for correct, p for present and a for absent. the
+    // letters themselves are never sent
+    pub rows: Vec<String>,
+    // how long the race took them, once they're done
+    pub finished_secs: Option<u64>,
+}
+
+impl Player {
+    fn won(&self) -> bool {
+        self.rows
+            .last()
+            .is_some_and(|row| row.chars().all(|state| state == 'c'))
+    }
+
+    // winners first by fewest guesses then quickest, then everyone still going, then losers
+    fn rank(&self) -> (u8, usize, u64) {
+        match self.finished_secs {
+            Some(secs) if self.won() => (0, self.rows.len(), secs),
+            Some(secs) => (2, 0, secs),
+            None => (1, 0, 0),
+        }
+    }
+
+    fn standing(&self) -> String {
+        match self.finished_secs {
+            Some(secs) if self.won() => format!(
+                "{}/{MAX_GUESSES} in {}",
+                self.rows.len(),
+                timer::format(Duration::from_secs(secs))
+            ),
+            Some(_) => format!("X/{MAX_GUESSES}"),
+            None if self.rows.is_empty() => String::from("thinking"),
+            None => format!("{} down", self.rows.len()),
+        }
+    }
+}
+
+const fn state_code(state: LetterState) -> char {
+    match state {
+        LetterState::Correct => 'c',
+        LetterState::Present => 'p',
+        LetterState::Absent => 'a',
+    }
+}
+
+const fn code_state(code: char) -> LetterState {
+    match code {
+        'c' => LetterState::Correct,
+        'p' => LetterState::Present,
+        _ => LetterState::Absent,
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Client {
+    url: String,
+    token: Option<String>,
+    agent: Agent,
+}
+
+impl Client {
+    fn authorized(&self, request: Request) -> Request {
+        match &self.token {
+            Some(token) => request.set("Authorization", &format!("Bearer {token}")),
+            None => request,
+        }
+    }
+
+    fn push(&self, player: &Player) -> Result<()> {
+        network::send_json(
+            &self.authorized(self.agent.post(&self.url)),
+            &serde_json::to_value(player)?,
+        )
+        .context("failed to reach the relay")?;
+        Ok(())
+    }
+
+    fn pull(&self) -> Result<Vec<Player>> {
+        network::call(&self.authorized(self.agent.get(&self.url)))
+            .context("failed to reach the relay")?
+            .into_json()
+            .context("the relay didn't send a list of players")
+    }
+}
+
+// talks to the relay in the background, sending only what's changed and polling for everyone
+// else until the game lets go of it
+#[derive(Debug)]
+struct Relay {
+    outgoing: Sender<Player>,
+    incoming: Receiver<Result<Vec<Player>, String>>,
+    thread: JoinHandle<()>,
+}
+
+impl Relay {
+    fn connect(client: Client) -> Self {
+        let (outgoing, outgoing_receiver) = mpsc::channel::<Player>();
+        let (incoming_sender, incoming) = mpsc::channel();
+
+        let thread = thread::spawn(move || {
+            let mut latest = None;
+            let mut sent = None;
+            let mut closing = false;
+            loop {
+                // once the game is closed whatever it last sent still goes out
+                loop {
+                    match outgoing_receiver.try_recv() {
+                        Ok(player) => latest = Some(player),
+                        Err(TryRecvError::Empty) => break,
+                        Err(TryRecvError::Disconnected) => {
+                            closing = true;
+                            break;
+                        }
+                    }
+                }
+
+                let mut result = Ok(());
+                if latest != sent {
+                    if let Some(player) = &latest {
+                        // anything that didn't make it is tried again next time round
+                        result = client.push(player);
+                        if result.is_ok() {
+                            sent.clone_from(&latest);
+                        }
+                    }
+                }
+                if closing {
+                    return;
+                }
+
+                let result = result
+                    .and_then(|()| client.pull())
+                    .map_err(|err| format!("{err:#}"));
+                // the receiver is only gone if the game was already closed
+                if incoming_sender.send(result).is_err() {
+                    return;
+                }
+
+                // a new guess is sent straight away rather than at the next poll
+                match outgoing_receiver.recv_timeout(POLL) {
+                    Ok(player) => latest = Some(player),
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Err(RecvTimeoutError::Disconnected) => closing = true,
+                }
+            }
+        });
+
+        Self {
+            outgoing,
+            incoming,
+            thread,
+        }
+    }
+
+    // lets go of the relay, giving the last push a moment to go out before the process exits
+    fn close(self) {
+        drop(self.outgoing);
+        let deadline = Instant::now() + CLOSE_TIMEOUT;
+        while !self.thread.is_finished() && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum View {
+    Lobby,
+    Playing,
+    Results,
+}
+
+// everyone in a room racing to solve the same word, each on their own keyboard
+#[derive(Debug)]
+struct Royale<'a> {
+    config: &'a Config,
+    room: String,
+    word_list: HashSet<String>,
+    solution: String,
+    word_length: usize,
+
+    me: Player,
+    // everyone the relay knows about, this player included
+    players: Vec<Player>,
+    relay: Relay,
+    // the last thing that went wrong talking to the relay, cleared once it works again
+    error: Option<String>,
+
+    board: Board,
+    input: String,
+    started: Option<Instant>,
+    animations: Animations,
+
+    view: View,
+    exit: bool,
+}
+
+pub fn run(
+    terminal: &mut Terminal,
+    config: &Config,
+    puzzle: Puzzle,
+    room: &str,
+    agent: &Agent,
+) -> Result<()> {
+    let url = config
+        .relay
+        .url
+        .as_ref()
+        .context("a battle royale needs a [relay] url in the config")?;
+    let client = Client {
+        url: format!("{}/rooms/{room}", url.trim_end_matches('/')),
+        token: config.relay.token.clone(),
+        agent: agent.clone(),
+    };
+
+    // the room picks the word, so everyone on the same word list gets the same one
+    let answers = answers::pool(&puzzle.word_list, &puzzle.answers, config.answer_pool);
+    let solution = answers::seeded(&answers, storage::hash(room.bytes()), 0)
+        .context("there are no answers to race on")?;
+
+    let me = Player {
+        id: format!("{:016x}", fastrand::u64(..)),
+        name: config.relay.name(),
+        ..Player::default()
+    };
+    let mut royale = Royale {
+        config,
+        room: room.to_owned(),
+        word_list: puzzle.word_list,
+        word_length: solution.chars().count(),
+        board: Board::new(Game::new(&solution, config.hard_mode)),
+        solution,
+        players: Vec::new(),
+        relay: Relay::connect(client),
+        error: None,
+        me,
+        input: String::new(),
+        started: None,
+        animations: Animations::new(config.animations)
+            .with_reveal_delay(config.streamer.reveal_delay()),
+        view: View::Lobby,
+        exit: false,
+    };
+    royale.send();
+
+    let mut events = Crossterm::new(config.tick_rate());
+    while !royale.exit {
+        royale.poll();
+        terminal.draw(|frame| royale.draw(frame))?;
+
+        match events.next()? {
+            Event::Key(key_event) => royale.handle_key_event(key_event),
+            Event::Paste(text) if royale.view == View::Playing => {
+                let _ = board::paste(&mut royale.input, &text, royale.word_length);
+            }
+            _ => {}
+        }
+    }
+
+    tui::restore();
+    for (place, player) in royale.standings().iter().enumerate() {
+        println!("{}. {} {}", place + 1, player.name, player.standing());
+    }
+    royale.relay.close();
+
+    Ok(())
+}
+
+impl Royale<'_> {
+    fn send(&self) {
+        // the relay thread only stops once the game lets go of it
+        let _ = self.relay.outgoing.send(self.me.clone());
+    }
+
+    fn poll(&mut self) {
+        loop {
+            match self.relay.incoming.try_recv() {
+                Ok(Ok(players)) => {
+                    self.players = players;
+                    self.error = None;
+                }
+                Ok(Err(err)) => self.error = Some(err),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    self.error = Some(String::from("lost the relay unexpectedly"));
+                    break;
+                }
+            }
+        }
+
+        // everyone the relay has heard from has to be ready, this player included
+        let everyone_ready = self.players.iter().any(|player| player.id == self.me.id)
+            && self.players.iter().all(|player| player.ready);
+        if self.view == View::Lobby && self.me.ready && everyone_ready {
+            self.started = Some(Instant::now());
+            self.view = View::Playing;
+        }
+    }
+
+    // the relay's copy of this player lags behind, so it's swapped for the real one
+    fn standings(&self) -> Vec<Player> {
+        let mut players = self
+            .players
+            .iter()
+            .filter(|player| player.id != self.me.id)
+            .cloned()
+            .chain([self.me.clone()])
+            .collect::<Vec<Player>>();
+        players.sort_by_key(Player::rank);
+        players
+    }
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) {
+        let keys = &self.config.keys;
+        if keys.quit.matches(key_event) {
+            self.exit = true;
+            return;
+        }
+
+        match self.view {
+            View::Lobby => match keys.action(key_event, &[Action::Submit, Action::Close]) {
+                Some(Action::Submit) => {
+                    self.me.ready = true;
+                    self.send();
+                }
+                Some(Action::Close) => self.exit = true,
+                _ => {}
+            },
+            View::Playing => match keys.action(
+                key_event,
+                &[Action::Submit, Action::Backspace, Action::ClearInput],
+            ) {
+                Some(Action::Submit) => self.submit_guess(),
+                Some(Action::Backspace) => {
+                    let _ = self.input.pop();
+                }
+                Some(Action::ClearInput) => self.input.clear(),
+                _ => {
+                    if let KeyCode::Char(c) = key_event.code {
+                        let _ = board::type_letter(&mut self.input, c, self.word_length);
+                    }
+                }
+            },
+            View::Results => {
+                if keys.action(key_event, &[Action::Close]).is_some() {
+                    self.exit = true;
+                }
+            }
+        }
+    }
+
+    fn submit_guess(&mut self) {
+        let Ok(guess) = self.board.submit(&self.input, &self.word_list) else {
+            self.animations.shake();
+            return;
+        };
+        self.input.clear();
+
+        self.animations
+            .reveal(self.board.game.guesses().len() - 1, self.word_length);
+        self.me
+            .rows
+            .push(guess.states.iter().copied().map(state_code).collect());
+        if self.board.game.status() != GameStatus::InProgress {
+            self.me.finished_secs = Some(
+                self.started
+                    .map_or(Duration::ZERO, |started| started.elapsed())
+                    .as_secs(),
+            );
+            self.view = View::Results;
+        }
+        self.send();
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let [title_area, main_area, bottom_area] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(1),
+            Constraint::Length(if self.view == View::Results { 3 } else { 1 }),
+        ])
+        .areas(frame.area());
+
+        let status = self.error.as_ref().map_or_else(
+            || {
+                Line::from(format!(
+                    "room {}, {} here",
+                    self.room,
+                    self.players.len().max(1)
+                ))
+                .dark_gray()
+            },
+            |err| Line::from(err.as_str()).fg(Color::LightRed),
+        );
+        let title = Paragraph::new(vec![Line::from("Wordle battle royale"), status])
+            .style(Style::default().fg(self.config.colors.title).dim())
+            .centered();
+        frame.render_widget(title, title_area);
+
+        if self.view == View::Lobby {
+            self.draw_lobby(frame, main_area);
+            return;
+        }
+
+        let [board_area, opponents_area] =
+            Layout::horizontal([Constraint::Ratio(1, 3), Constraint::Ratio(2, 3)]).areas(main_area);
+        self.draw_board(frame, board_area);
+        self.draw_opponents(frame, opponents_area);
+
+        if self.view == View::Results && !self.animations.is_revealing() {
+            self.draw_results(frame, bottom_area);
+        }
+    }
+
+    fn draw_lobby(&self, frame: &mut Frame, area: Rect) {
+        let [_, lobby_area] =
+            Layout::vertical([Constraint::Percentage(20), Constraint::Min(1)]).areas(area);
+
+        let mut lines = vec![Line::from("in the lobby").bold(), Line::default()];
+        lines.extend(self.standings().iter().map(|player| {
+            let line = Line::from(format!(
+                "{:<24}{:<7}",
+                player.name,
+                if player.ready { "ready" } else { "waiting" }
+            ));
+            if player.ready {
+                line.fg(self.config.colors.correct)
+            } else {
+                line.dark_gray()
+            }
+        }));
+        lines.push(Line::default());
+        lines.push(
+            Line::from(if self.me.ready {
+                format!(
+                    "the race starts once everyone's ready, {} to leave",
+                    self.config.keys.close.primary()
+                )
+            } else {
+                format!(
+                    "{} when you're ready, {} to leave",
+                    self.config.keys.submit.primary(),
+                    self.config.keys.close.primary()
+                )
+            })
+            .style(Style::default().fg(Color::DarkGray).dim()),
+        );
+
+        frame.render_widget(Paragraph::new(lines).centered(), lobby_area);
+    }
+
+    fn draw_board(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::bordered()
+            .title(Line::from(format!(" {} ", self.me.name)).centered())
+            .border_style(Style::default().fg(if self.view == View::Playing {
+                self.config.colors.title
+            } else {
+                Color::DarkGray
+            }));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let [guesses_area, input_area] =
+            Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(inner);
+        frame.render_widget(
+            self.board.guesses_list(
+                &self.config.colors,
+                |row| self.animations.revealed_tiles(row),
+                false,
+            ),
+            guesses_area,
+        );
+
+        if self.view == View::Playing {
+            let input_line = self
+                .animations
+                .shake_line(self.board.input_line(&self.input, &self.config.colors));
+            frame.render_widget(Paragraph::new(input_line).centered(), input_area);
+        }
+    }
+
+    // as many columns of emoji boards as fit, filled top to bottom
+    fn draw_opponents(&self, frame: &mut Frame, area: Rect) {
+        let opponents = self
+            .players
+            .iter()
+            .filter(|player| player.id != self.me.id)
+            .collect::<Vec<&Player>>();
+        if opponents.is_empty() {
+            let waiting = Paragraph::new("nobody else is here").dark_gray().centered();
+            frame.render_widget(waiting, area);
+            return;
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        let height = MAX_GUESSES as u16 + 3;
+        let per_column = usize::from((area.height / height).max(1));
+        let columns = opponents.chunks(per_column).collect::<Vec<&[&Player]>>();
+        let column_areas =
+            Layout::horizontal(vec![Constraint::Length(OPPONENT_WIDTH); columns.len()])
+                .horizontal_margin(2)
+                .split(area);
+
+        for (column, players) in columns.iter().enumerate() {
+            let Some(&column_area) = column_areas.get(column) else {
+                break;
+            };
+            let lines = players
+                .iter()
+                .flat_map(|player| self.opponent_lines(player))
+                .collect::<Vec<Line>>();
+            frame.render_widget(Paragraph::new(lines), column_area);
+        }
+    }
+
+    fn opponent_lines(&self, player: &Player) -> Vec<Line<'static>> {
+        let color = match player.finished_secs {
+            Some(_) if player.won() => self.config.colors.correct,
+            Some(_) => Color::LightRed,
+            None => Color::White,
+        };
+        let mut lines = vec![
+            Line::from(truncate_name(&player.name)).bold(),
+            Line::from(player.standing()).fg(color),
+        ];
+        lines.extend((0..MAX_GUESSES).map(|row| {
+            player.rows.get(row).map_or_else(
+                // as wide as the emoji, which take up two cells each
+                || Line::from("· ".repeat(self.word_length)).dark_gray(),
+                |states| {
+                    Line::from(
+                        states
+                            .chars()
+                            .map(|code| self.config.emoji_style.emoji(code_state(code)))
+                            .collect::<String>(),
+                    )
+                },
+            )
+        }));
+        lines.push(Line::default());
+        lines
+    }
+
+    fn draw_results(&self, frame: &mut Frame, area: Rect) {
+        let standings = self.standings();
+        let place = standings
+            .iter()
+            .position(|player| player.id == self.me.id)
+            .unwrap_or_default();
+        let mut lines = vec![if self.board.game.status() == GameStatus::Won {
+            Line::from(format!(
+                "solved in {}/{MAX_GUESSES}, {} of {} so far",
+                self.board.game.guesses().len(),
+                ordinal(place + 1),
+                standings.len()
+            ))
+            .fg(self.config.colors.correct)
+            .bold()
+        } else {
+            Line::from(vec![
+                Span::from("the word was "),
+                Span::from(self.solution.clone()).bold(),
+            ])
+            .fg(Color::LightRed)
+        }];
+        lines.push(
+            Line::from(format!(
+                "the others are still shown as they finish, {} to quit",
+                self.config.keys.close.primary()
+            ))
+            .style(Style::default().fg(Color::DarkGray).dim()),
+        );
+
+        frame.render_widget(Paragraph::new(lines).centered(), area);
+    }
+}
+
+fn truncate_name(name: &str) -> String {
+    name.chars().take(usize::from(OPPONENT_WIDTH) - 2).collect()
+}
+
+fn ordinal(place: usize) -> String {
+    let suffix = match (place % 10, place % 100) {
+        (1, 11) | (2, 12) | (3, 13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{place}{suffix}")
+}
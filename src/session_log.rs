@@ -0,0 +1,143 @@
+use anyhow::{Context, Result};
+use std::{
+    fmt::{self, Debug, Display},
+    fs::{self, File},
+    path::Path,
+    sync::Mutex,
+};
+use tracing::{
+    field::{Field, Visit},
+    Level, Subscriber,
+};
+use tracing_subscriber::{
+    field::RecordFields,
+    filter::Targets,
+    fmt::{format::Writer, time::ChronoLocal, FormatFields},
+    layer::SubscriberExt,
+    util::SubscriberInitExt,
+};
+
+use crate::challenge;
+
+// the log is kept on disk and attached to bug reports, so words from the game never go in as
+// they are. they're logged as these fields and written obscured like in the stats, which only
+// whoever reads the report bothers getting back
+const WORD_FIELDS: [&str; 2] = ["guess", "solution"];
+
+// what happened during a game, for bug reports. the game's own goings on are written at debug
+// level, everything else (ureq and rustls, through their `log` records) only from info unless
+// it's `verbose`. written as it goes, so whatever happened before a crash is already in the file
+pub fn start(path: &Path, verbose: bool) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    let file =
+        File::create(path).with_context(|| format!("failed to create {}", path.display()))?;
+
+    subscriber(file, verbose)
+        .try_init()
+        .context("a log was already started")?;
+    tracing::info!(
+        "wordle-tui {} started with {:?}",
+        env!("CARGO_PKG_VERSION"),
+        std::env::args().skip(1).collect::<Vec<String>>()
+    );
+
+    Ok(())
+}
+
+fn subscriber(file: File, verbose: bool) -> impl Subscriber + Send + Sync {
+    let filter = Targets::new()
+        .with_target("wordle_tui", Level::DEBUG)
+        .with_default(if verbose { Level::TRACE } else { Level::INFO });
+    tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_writer(Mutex::new(file))
+                .with_timer(ChronoLocal::new(String::from("%H:%M:%S%.3f")))
+                .with_ansi(false)
+                .fmt_fields(Fields),
+        )
+        .with(filter)
+}
+
+// the same `message name=value ...` as usual, with the words obscured
+struct Fields;
+
+impl<'writer> FormatFields<'writer> for Fields {
+    fn format_fields<R: RecordFields>(&self, writer: Writer<'writer>, fields: R) -> fmt::Result {
+        let mut visitor = FieldVisitor {
+            writer,
+            result: Ok(()),
+            delimit: false,
+        };
+        fields.record(&mut visitor);
+        visitor.result
+    }
+}
+
+struct FieldVisitor<'writer> {
+    writer: Writer<'writer>,
+    result: fmt::Result,
+    delimit: bool,
+}
+
+impl FieldVisitor<'_> {
+    fn write(&mut self, field: &Field, value: &dyn Display) {
+        // where a `log` record came from, already written as its target
+        if self.result.is_err() || field.name().starts_with("log.") {
+            return;
+        }
+        if self.delimit {
+            self.result = self.writer.write_char(' ');
+        }
+        self.delimit = true;
+
+        self.result = self.result.and_then(|()| match field.name() {
+            "message" => write!(self.writer, "{value}"),
+            name if WORD_FIELDS.contains(&name) => {
+                write!(
+                    self.writer,
+                    "{name}={}",
+                    challenge::obscure(&value.to_string())
+                )
+            }
+            name => write!(self.writer, "{name}={value}"),
+        });
+    }
+}
+
+impl Visit for FieldVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.write(field, &value);
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.write(field, &format_args!("{value:?}"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn words_are_written_obscured() {
+        let path = env::temp_dir().join(format!("wordle-tui-log-{}", fastrand::u64(..)));
+        let file = File::create(&path).unwrap();
+        tracing::subscriber::with_default(subscriber(file, false), || {
+            tracing::debug!(guess = "CRANE", solution = %"SLATE", tries = 2, "scored");
+        });
+        let log = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert!(log.contains(&format!(
+            "scored guess={} solution={} tries=2",
+            challenge::obscure("CRANE"),
+            challenge::obscure("SLATE")
+        )));
+        assert!(!log.contains("CRANE") && !log.contains("SLATE"), "{log}");
+    }
+}
@@ -0,0 +1,262 @@
+use anyhow::{bail, Context, Result};
+use ratatui::{
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Clear, Padding, Paragraph},
+    Frame,
+};
+use std::{fs, path::Path};
+use toml::{Table, Value};
+
+use crate::{
+    config::{Colors, Config},
+    help, storage,
+};
+
+const THEMES: [&str; 3] = ["classic", "high contrast", "no color"];
+
+// what can be changed without restarting, everything else is still only in the config file
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Setting {
+    HardMode,
+    Theme,
+    Filled,
+    Animations,
+    Timer,
+    Assist,
+    Accessible,
+    DoubleLetters,
+    KeyboardLayout,
+    ConfirmQuit,
+}
+
+impl Setting {
+    pub const ALL: [Self; 10] = [
+        Self::HardMode,
+        Self::Theme,
+        Self::Filled,
+        Self::Animations,
+        Self::Timer,
+        Self::Assist,
+        Self::Accessible,
+        Self::DoubleLetters,
+        Self::KeyboardLayout,
+        Self::ConfirmQuit,
+    ];
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::HardMode => "hard mode",
+            Self::Theme => "theme",
+            Self::Filled => "filled tiles",
+            Self::Animations => "animations",
+            Self::Timer => "timer",
+            Self::Assist => "allow peeking",
+            Self::Accessible => "describe guesses",
+            Self::DoubleLetters => "mark double letters",
+            Self::KeyboardLayout => "keyboard layout",
+            Self::ConfirmQuit => "confirm quit",
+        }
+    }
+
+    fn value(self, config: &Config) -> String {
+        let on_off = |on| String::from(if on { "on" } else { "off" });
+        match self {
+            Self::HardMode => on_off(config.hard_mode),
+            Self::Theme => String::from(theme(&config.colors).map_or("custom", |i| THEMES[i])),
+            Self::Filled => on_off(config.colors.filled),
+            Self::Animations => on_off(config.animations),
+            Self::Timer => on_off(config.timer),
+            Self::Assist => on_off(config.assist),
+            Self::Accessible => on_off(config.accessible),
+            Self::DoubleLetters => on_off(config.double_letters),
+            Self::KeyboardLayout => String::from(config.keyboard_layout.name()),
+            Self::ConfirmQuit => on_off(config.confirm_quit),
+        }
+    }
+
+    // flips it, or moves on to the next theme or layout
+    fn change(self, config: &mut Config) {
+        match self {
+            Self::HardMode => config.hard_mode = !config.hard_mode,
+            Self::Theme => {
+                let next = theme(&config.colors).map_or(0, |i| (i + 1) % THEMES.len());
+                let filled = config.colors.filled;
+                config.colors = match next {
+                    0 => Colors::default(),
+                    1 => Colors::high_contrast(),
+                    _ => Colors::without_color(),
+                };
+                // there's nothing to fill with when there's no color
+                config.colors.filled = filled && next != 2;
+            }
+            Self::Filled => config.colors.filled = !config.colors.filled,
+            Self::Animations => config.animations = !config.animations,
+            Self::Timer => config.timer = !config.timer,
+            Self::Assist => config.assist = !config.assist,
+            Self::Accessible => config.accessible = !config.accessible,
+            Self::DoubleLetters => config.double_letters = !config.double_letters,
+            Self::KeyboardLayout => config.keyboard_layout = config.keyboard_layout.next(),
+            Self::ConfirmQuit => config.confirm_quit = !config.confirm_quit,
+        }
+    }
+
+    // the keys in the config file this setting is written to, under `[colors]` if it's there
+    fn save(self, config: &Config, file: &mut Table) -> Result<()> {
+        let table = |file: &mut Table, name: &str| -> Result<Table> {
+            match file.remove(name) {
+                Some(Value::Table(table)) => Ok(table),
+                Some(_) => bail!("{name} in the config file isn't a table"),
+                None => Ok(Table::new()),
+            }
+        };
+
+        match self {
+            Self::HardMode => set(file, "hard_mode", config.hard_mode),
+            Self::Animations => set(file, "animations", config.animations),
+            Self::Timer => set(file, "timer", config.timer),
+            Self::Assist => set(file, "assist", config.assist),
+            Self::Accessible => set(file, "accessible", config.accessible),
+            Self::DoubleLetters => set(file, "double_letters", config.double_letters),
+            Self::ConfirmQuit => set(file, "confirm_quit", config.confirm_quit),
+            Self::KeyboardLayout => {
+                file.insert(
+                    String::from("keyboard_layout"),
+                    Value::String(config.keyboard_layout.name().to_owned()),
+                );
+            }
+            Self::Theme | Self::Filled => {
+                let mut colors = table(file, "colors")?;
+                let Value::Table(all) = Value::try_from(&config.colors)? else {
+                    bail!("colors didn't turn into a table");
+                };
+                let keys: &[&str] = match self {
+                    Self::Filled => &["filled"],
+                    _ => &["correct", "wrong_placement", "absent", "title", "symbols"],
+                };
+                for &key in keys {
+                    if let Some(value) = all.get(key) {
+                        colors.insert(key.to_owned(), value.clone());
+                    }
+                }
+                file.insert(String::from("colors"), Value::Table(colors));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn set(file: &mut Table, key: &str, value: bool) {
+    file.insert(key.to_owned(), Value::Boolean(value));
+}
+
+// which of the built in themes the colors are, if any
+fn theme(colors: &Colors) -> Option<usize> {
+    [
+        Colors::default(),
+        Colors::high_contrast(),
+        Colors::without_color(),
+    ]
+    .iter()
+    .position(|theme| {
+        (
+            theme.correct,
+            theme.wrong_placement,
+            theme.absent,
+            theme.symbols,
+        ) == (
+            colors.correct,
+            colors.wrong_placement,
+            colors.absent,
+            colors.symbols,
+        )
+    })
+}
+
+#[derive(Debug, Default)]
+pub struct Settings {
+    selected: usize,
+}
+
+impl Settings {
+    pub const fn up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn down(&mut self) {
+        self.selected = (self.selected + 1).min(Setting::ALL.len() - 1);
+    }
+
+    // changes the selected setting and writes just that to the config file at `path`,
+    // returning it so the game can catch up
+    pub fn change(&self, config: &mut Config, path: Option<&Path>) -> Result<Setting> {
+        let setting = Setting::ALL[self.selected];
+        setting.change(config);
+
+        let Some(path) = path else {
+            return Ok(setting);
+        };
+        // rewritten from what's in the file rather than `config`, so flags given this time
+        // aren't saved along with it
+        let mut file = match fs::read_to_string(path) {
+            Ok(contents) => toml::from_str::<Table>(&contents)
+                .with_context(|| format!("failed to parse {}", path.display()))?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Table::new(),
+            Err(err) => {
+                return Err(err).with_context(|| format!("failed to read {}", path.display()))
+            }
+        };
+        setting.save(config, &mut file)?;
+        storage::write(path, toml::to_string(&file)?)?;
+
+        Ok(setting)
+    }
+}
+
+pub fn render(settings: &Settings, config: &Config, path: Option<&Path>, frame: &mut Frame) {
+    let mut lines = Setting::ALL
+        .into_iter()
+        .enumerate()
+        .map(|(i, setting)| {
+            let line = Line::from(vec![
+                Span::from(if i == settings.selected { "> " } else { "  " }),
+                Span::from(format!("{:<20}", setting.name())),
+                Span::from(setting.value(config)),
+            ]);
+            if i == settings.selected {
+                line.fg(config.colors.title).bold()
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<Line>>();
+    lines.push(Line::default());
+    lines.push(
+        Line::from(path.map_or_else(
+            || String::from("there's no config file to save these to"),
+            |path| format!("saved to {}", path.display()),
+        ))
+        .dark_gray(),
+    );
+
+    #[allow(clippy::cast_possible_truncation)]
+    let area = help::centered(frame.area(), help::WIDTH, lines.len() as u16 + 2);
+
+    let popup = Paragraph::new(lines).block(
+        Block::bordered()
+            .title(" settings ")
+            .title_bottom(
+                Line::from(format!(
+                    " ↑/↓ to choose, space to change, {} to close ",
+                    config.keys.close.primary()
+                ))
+                .centered(),
+            )
+            .border_style(Style::default().fg(Color::DarkGray))
+            .padding(Padding::horizontal(1)),
+    );
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
+}
@@ -0,0 +1,259 @@
+use anyhow::{Context, Result};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Padding, Paragraph},
+    Frame,
+};
+use serde::Serialize;
+use std::{
+    io::{self, IsTerminal},
+    path::{self, Path, PathBuf},
+};
+use wordle_tui::wordle_core::LetterState;
+
+use crate::{
+    cli::Args,
+    color_support::ColorSupport,
+    config::{Colors, Config, EmojiStyle, Paths},
+    help, storage,
+    tui::Tui,
+};
+
+const THEMES: [&str; 3] = ["classic", "high contrast", "no color"];
+const HARD_MODE: [&str; 2] = ["no", "yes"];
+const EMOJI_STYLES: [&str; 3] = ["light", "dark", "high contrast"];
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Step {
+    Theme,
+    HardMode,
+    EmojiStyle,
+    DataDir,
+}
+
+impl Step {
+    const ALL: [Self; 4] = [Self::Theme, Self::HardMode, Self::EmojiStyle, Self::DataDir];
+
+    const fn question(self) -> &'static str {
+        match self {
+            Self::Theme => "how should the board look?",
+            Self::HardMode => "play in hard mode? revealed hints have to be used again",
+            Self::EmojiStyle => "which emoji should the share grid use?",
+            Self::DataDir => "where should stats and saved games go?",
+        }
+    }
+
+    const fn options(self) -> &'static [&'static str] {
+        match self {
+            Self::Theme => &THEMES,
+            Self::HardMode => &HARD_MODE,
+            Self::EmojiStyle => &EMOJI_STYLES,
+            Self::DataDir => &[],
+        }
+    }
+}
+
+// just what was asked about, everything else keeps following the defaults
+#[derive(Serialize)]
+struct Answers<'a> {
+    hard_mode: bool,
+    emoji_style: EmojiStyle,
+    colors: &'a Colors,
+    paths: &'a Paths,
+}
+
+#[derive(Debug)]
+struct Setup {
+    step: usize,
+    // the picked option of every step that has any
+    choices: [usize; Step::ALL.len()],
+    data_dir: String,
+}
+
+impl Setup {
+    const fn current(&self) -> Step {
+        Step::ALL[self.step]
+    }
+
+    fn config(&self) -> Config {
+        let data_dir = self.data_dir.trim();
+        let data_dir = data_dir.strip_prefix("~/").map_or_else(
+            || PathBuf::from(data_dir),
+            |rest| dirs::home_dir().unwrap_or_default().join(rest),
+        );
+        // written out in full so it doesn't depend on where the game happens to be run from
+        let data_dir = path::absolute(&data_dir).unwrap_or(data_dir);
+
+        Config {
+            colors: match self.choices[0] {
+                0 => Colors::default(),
+                1 => Colors::high_contrast(),
+                _ => Colors::without_color(),
+            },
+            hard_mode: self.choices[1] == 1,
+            emoji_style: match self.choices[2] {
+                0 => EmojiStyle::Light,
+                1 => EmojiStyle::Dark,
+                _ => EmojiStyle::HighContrast,
+            },
+            paths: Paths::in_dir(&data_dir),
+            ..Config::default()
+        }
+    }
+
+    // returns some once every step is answered, with whether to go ahead
+    fn handle_key_event(&mut self, key_event: KeyEvent) -> Option<bool> {
+        let step = self.current();
+        let choice = &mut self.choices[self.step];
+
+        match key_event.code {
+            KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                return Some(false);
+            }
+            KeyCode::Enter if self.step + 1 < Step::ALL.len() => self.step += 1,
+            KeyCode::Enter if !self.data_dir.trim().is_empty() => return Some(true),
+            KeyCode::Esc => self.step = self.step.saturating_sub(1),
+            KeyCode::Backspace if step == Step::DataDir => {
+                let _ = self.data_dir.pop();
+            }
+            KeyCode::Char(c) if step == Step::DataDir => self.data_dir.push(c),
+            KeyCode::Up | KeyCode::Char('k') => *choice = choice.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                *choice = (*choice + 1).min(step.options().len().saturating_sub(1));
+            }
+            _ => {}
+        }
+
+        None
+    }
+}
+
+// the config file setup would write, if there isn't one yet and there's a terminal to ask in
+pub fn needed(args: &Args) -> Option<PathBuf> {
+    if args.no_tui
+        || args.bench.is_some()
+        || args.simulate.is_some()
+        || args.wordlist.is_some()
+        || args.user_data.is_some()
+        || args.create_challenge.is_some()
+        || !io::stdout().is_terminal()
+    {
+        return None;
+    }
+
+    let path = args.config.clone().or_else(Config::default_path)?;
+    (!path.exists()).then_some(path)
+}
+
+// asks a few questions and writes the answers to `path` before anything else touches the disk.
+// returns whether to carry on, nothing is written if the player quit instead
+pub fn run(path: &Path) -> Result<bool> {
+    let mut setup = Setup {
+        step: 0,
+        choices: [0; Step::ALL.len()],
+        data_dir: storage::data_dir().display().to_string(),
+    };
+
+    let mut terminal = Tui::init(false, ColorSupport::Auto.detect())?;
+    let carry_on = loop {
+        terminal.draw(|frame| draw(frame, &setup, path))?;
+
+        let Event::Key(key_event) = event::read()? else {
+            continue;
+        };
+        if key_event.kind != KeyEventKind::Press {
+            continue;
+        }
+        if let Some(carry_on) = setup.handle_key_event(key_event) {
+            break carry_on;
+        }
+    };
+    drop(terminal);
+
+    if !carry_on {
+        return Ok(false);
+    }
+
+    let config = setup.config();
+    let answers = Answers {
+        hard_mode: config.hard_mode,
+        emoji_style: config.emoji_style,
+        colors: &config.colors,
+        paths: &config.paths,
+    };
+    storage::write(
+        path,
+        format!(
+            "# written by the first run setup, every other option is in the readme\n{}",
+            toml::to_string(&answers).context("failed to write the config")?
+        ),
+    )?;
+
+    Ok(true)
+}
+
+fn draw(frame: &mut Frame, setup: &Setup, path: &Path) {
+    let step = setup.current();
+    let colors = setup.config().colors;
+
+    let mut lines = vec![Line::from(step.question()).bold(), Line::default()];
+    if step == Step::DataDir {
+        lines.push(Line::from(vec![
+            Span::from("> "),
+            Span::from(setup.data_dir.as_str()).fg(colors.title),
+            Span::from("_").dim(),
+        ]));
+    } else {
+        lines.extend(step.options().iter().enumerate().map(|(i, option)| {
+            if i == setup.choices[setup.step] {
+                Line::from(format!("> {option}")).fg(colors.title).bold()
+            } else {
+                Line::from(format!("  {option}"))
+            }
+        }));
+    }
+    lines.push(Line::default());
+
+    // a taste of whatever's picked so far
+    let sample = "CRANE"
+        .chars()
+        .zip([
+            LetterState::Correct,
+            LetterState::Present,
+            LetterState::Absent,
+            LetterState::Absent,
+            LetterState::Correct,
+        ])
+        .map(|(c, state)| colors.letter(c, Some(state)).bold())
+        .collect::<Vec<Span>>();
+    lines.push(Line::from(sample).centered());
+
+    #[allow(clippy::cast_possible_truncation)]
+    let area = help::centered(frame.area(), help::WIDTH, lines.len() as u16 + 2);
+    let [_, hint_area] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::bordered()
+                .title(format!(" setup {}/{} ", setup.step + 1, Step::ALL.len()))
+                .title_bottom(Line::from(format!(" saved to {} ", path.display())).centered())
+                .border_style(Style::default().fg(Color::DarkGray))
+                .padding(Padding::horizontal(1)),
+        ),
+        area,
+    );
+    frame.render_widget(
+        Paragraph::new(if step == Step::DataDir {
+            "type a path, enter to finish, esc to go back, ctrl+c to quit"
+        } else {
+            "↑/↓ to choose, enter to continue, esc to go back, ctrl+c to quit"
+        })
+        .style(Style::default().fg(Color::DarkGray).dim())
+        .centered(),
+        hint_area,
+    );
+}
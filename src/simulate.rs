@@ -0,0 +1,116 @@
+use anyhow::{bail, Result};
+use fastrand::Rng;
+use std::{cmp::Ordering, collections::HashSet, fmt::Write, time::Instant};
+use wordle_tui::wordle_core::MAX_GUESSES;
+
+use crate::bench::{self, Strategy};
+
+pub const GAMES: usize = 500;
+
+// how one strategy did over every game
+struct Results {
+    strategy: Strategy,
+    guesses: Vec<Option<usize>>,
+    seconds: f64,
+}
+
+// plays the same `games` answers with each strategy and prints them side by side, then how
+// each pair did against each other game by game. the same seed picks the same answers
+pub fn run(
+    word_list: &HashSet<String>,
+    answers: &HashSet<String>,
+    strategies: &[Strategy],
+    games: usize,
+    seed: u64,
+    hard_mode: bool,
+) -> Result<()> {
+    let mut solutions = answers.iter().map(String::as_str).collect::<Vec<&str>>();
+    if solutions.is_empty() {
+        bail!("there are no answers to play");
+    }
+    solutions.sort_unstable();
+    Rng::with_seed(seed).shuffle(&mut solutions);
+    solutions.truncate(games);
+
+    let results = strategies
+        .iter()
+        .map(|&strategy| {
+            let started = Instant::now();
+            let guesses = bench::play_all(&solutions, word_list, strategy.solver(), hard_mode);
+            Results {
+                strategy,
+                guesses,
+                seconds: started.elapsed().as_secs_f64(),
+            }
+        })
+        .collect::<Vec<Results>>();
+
+    println!(
+        "{} games{}, seed {seed}",
+        solutions.len(),
+        if hard_mode { " in hard mode" } else { "" }
+    );
+    println!();
+    for line in table(&results) {
+        println!("{line}");
+    }
+
+    if results.len() > 1 {
+        println!();
+    }
+    for (i, first) in results.iter().enumerate() {
+        for second in &results[i + 1..] {
+            println!("{}", head_to_head(first, second));
+        }
+    }
+
+    Ok(())
+}
+
+// strategy   solved  average    1    2    3    4    5    6    X   time
+#[allow(clippy::cast_precision_loss)]
+fn table(results: &[Results]) -> Vec<String> {
+    let mut header = format!("{:<10} {:>7} {:>8}", "strategy", "solved", "average");
+    for guesses in 1..=MAX_GUESSES {
+        let _ = write!(header, " {guesses:>4}");
+    }
+    let _ = write!(header, " {:>4} {:>6}", "X", "time");
+
+    let mut lines = vec![header];
+    for result in results {
+        let solved = result.guesses.iter().flatten().collect::<Vec<&usize>>();
+        let average = solved.iter().copied().sum::<usize>() as f64 / solved.len().max(1) as f64;
+        let mut line = format!(
+            "{:<10} {:>6.1}% {average:>8.2}",
+            result.strategy.name(),
+            solved.len() as f64 / result.guesses.len().max(1) as f64 * 100.0
+        );
+        for count in bench::distribution(&result.guesses) {
+            let _ = write!(line, " {count:>4}");
+        }
+        let _ = write!(line, " {:>5.1}s", result.seconds);
+        lines.push(line);
+    }
+
+    lines
+}
+
+// fewer guesses wins a game, running out loses it
+fn head_to_head(first: &Results, second: &Results) -> String {
+    let (mut wins, mut losses, mut ties) = (0, 0, 0);
+    for (a, b) in first.guesses.iter().zip(&second.guesses) {
+        let a = a.unwrap_or(usize::MAX);
+        let b = b.unwrap_or(usize::MAX);
+        match a.cmp(&b) {
+            Ordering::Less => wins += 1,
+            Ordering::Greater => losses += 1,
+            Ordering::Equal => ties += 1,
+        }
+    }
+
+    format!(
+        "{} against {}: {wins} won, {losses} lost, {ties} tied",
+        first.strategy.name(),
+        second.strategy.name()
+    )
+}
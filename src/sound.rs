@@ -0,0 +1,154 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+};
+
+use crate::animation::Animations;
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Backend {
+    #[default]
+    Off,
+    // the terminal's bell, whatever it's set to do with it
+    Bell,
+    // sound files played with paplay on linux and the bsds, afplay on macos
+    Player,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Effect {
+    Invalid,
+    // a tile flipping over
+    Reveal,
+    Win,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Sound {
+    pub backend: Backend,
+    pub invalid: bool,
+    pub reveal: bool,
+    pub win: bool,
+    // what the player plays, the desktop's own sounds when unset
+    pub invalid_file: Option<PathBuf>,
+    pub reveal_file: Option<PathBuf>,
+    pub win_file: Option<PathBuf>,
+}
+
+impl Default for Sound {
+    fn default() -> Self {
+        Self {
+            backend: Backend::default(),
+            invalid: true,
+            // one for every tile is a lot with the bell
+            reveal: false,
+            win: true,
+            invalid_file: None,
+            reveal_file: None,
+            win_file: None,
+        }
+    }
+}
+
+// sounds that come with the system, so the player works without any set up
+const fn system_file(effect: Effect) -> &'static str {
+    if cfg!(target_os = "macos") {
+        match effect {
+            Effect::Invalid => "/System/Library/Sounds/Basso.aiff",
+            Effect::Reveal => "/System/Library/Sounds/Tink.aiff",
+            Effect::Win => "/System/Library/Sounds/Glass.aiff",
+        }
+    } else {
+        match effect {
+            Effect::Invalid => "/usr/share/sounds/freedesktop/stereo/dialog-error.oga",
+            Effect::Reveal => "/usr/share/sounds/freedesktop/stereo/audio-volume-change.oga",
+            Effect::Win => "/usr/share/sounds/freedesktop/stereo/complete.oga",
+        }
+    }
+}
+
+impl Sound {
+    // nothing is said if it couldn't be played, like notifications
+    pub fn play(&self, effect: Effect) {
+        let (enabled, file) = match effect {
+            Effect::Invalid => (self.invalid, &self.invalid_file),
+            Effect::Reveal => (self.reveal, &self.reveal_file),
+            Effect::Win => (self.win, &self.win_file),
+        };
+        if !enabled {
+            return;
+        }
+
+        match self.backend {
+            Backend::Off => {}
+            Backend::Bell => {
+                let mut stdout = io::stdout();
+                let _ = write!(stdout, "\x07").and_then(|()| stdout.flush());
+            }
+            Backend::Player => {
+                let file = file
+                    .as_deref()
+                    .unwrap_or_else(|| Path::new(system_file(effect)));
+                let player = if cfg!(target_os = "macos") {
+                    "afplay"
+                } else {
+                    "paplay"
+                };
+                // anything it prints would end up drawn over the game
+                let child = Command::new(player)
+                    .arg(file)
+                    .stdin(Stdio::null())
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::null())
+                    .spawn();
+                if let Ok(mut child) = child {
+                    thread::spawn(move || child.wait());
+                }
+            }
+        }
+    }
+}
+
+// keeps sounds in step with the animations, so a tile is heard when it flips and a win once
+// the last one has
+#[derive(Debug, Default)]
+pub struct Sounds {
+    // the row being revealed, its tile count and how many of those have been heard
+    reveal: Option<(usize, usize, usize)>,
+    after_reveal: Option<Effect>,
+}
+
+impl Sounds {
+    pub const fn reveal(&mut self, row: usize, tiles: usize) {
+        self.reveal = Some((row, tiles, 0));
+    }
+
+    pub const fn after_reveal(&mut self, effect: Effect) {
+        self.after_reveal = Some(effect);
+    }
+
+    pub fn tick(&mut self, sound: &Sound, animations: &Animations) {
+        if let Some((row, tiles, heard)) = &mut self.reveal {
+            // all at once with animations off, which is only heard once
+            let flipped = animations.revealed_tiles(*row).min(*tiles);
+            if flipped > *heard {
+                *heard = flipped;
+                sound.play(Effect::Reveal);
+            }
+            if *heard == *tiles {
+                self.reveal = None;
+            }
+        }
+
+        if !animations.is_revealing() {
+            if let Some(effect) = self.after_reveal.take() {
+                sound.play(effect);
+            }
+        }
+    }
+}
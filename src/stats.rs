@@ -0,0 +1,907 @@
+use anyhow::Result;
+use chrono::{Datelike, Days, Months, NaiveDate};
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Bar, BarChart, BarGroup, Block, Clear, Padding, Paragraph, Wrap},
+    Frame,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap, HashSet},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use wordle_tui::wordle_core::{score_guess, LetterState, FORFEITED};
+
+use crate::{
+    challenge,
+    config::Config,
+    help, keyboard,
+    storage::{self, Contents},
+    timer,
+};
+
+// bumped whenever a field changes meaning, new fields just need a default
+const VERSION: u32 = 1;
+
+// about half a year of days, which fits in the same width as the help popup
+const CALENDAR_WEEKS: u64 = 26;
+// how many weeks or months back the trends go
+const TREND_PERIODS: usize = 6;
+// the longest bar in the trends
+const BAR_WIDTH: usize = 30;
+// so one lucky game doesn't make for the best month ever
+const BEST_MIN_WINS: usize = 3;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GameRecord {
+    pub date: NaiveDate,
+    pub won: bool,
+    pub guesses: u8,
+    pub duration: Option<Duration>,
+    // kept for replays, older records don't have them
+    pub answer: Option<String>,
+    pub words: Vec<String>,
+    // how long was spent on each of `words`, also missing from older records
+    pub think_times: Vec<Duration>,
+}
+
+// a record as it's written to the stats file. the answer and words are always obscured, it's
+// kept next to the play state and shouldn't give away today's word any more than that does
+#[derive(Deserialize, Serialize)]
+struct StoredRecord {
+    date: NaiveDate,
+    won: bool,
+    guesses: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    duration_secs: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    answer: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    words: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    think_secs: Vec<u64>,
+    // older versions wrote them as they were outside streamer mode
+    #[serde(default)]
+    obscured: bool,
+}
+
+#[derive(Serialize)]
+struct StatsFile {
+    records: Vec<StoredRecord>,
+}
+
+impl From<&GameRecord> for StoredRecord {
+    fn from(record: &GameRecord) -> Self {
+        Self {
+            date: record.date,
+            won: record.won,
+            guesses: record.guesses,
+            duration_secs: record.duration.map(|duration| duration.as_secs()),
+            answer: record.answer.as_deref().map(challenge::obscure),
+            words: record
+                .words
+                .iter()
+                .map(|word| challenge::obscure(word))
+                .collect(),
+            think_secs: record.think_times.iter().map(Duration::as_secs).collect(),
+            obscured: true,
+        }
+    }
+}
+
+impl From<StoredRecord> for GameRecord {
+    // anything that can't be unobscured is dropped, the result still counts
+    fn from(stored: StoredRecord) -> Self {
+        let read = |field: String| {
+            if stored.obscured {
+                challenge::unobscure(&field)
+            } else {
+                Some(field)
+            }
+        };
+
+        Self {
+            date: stored.date,
+            won: stored.won,
+            guesses: stored.guesses,
+            duration: stored.duration_secs.map(Duration::from_secs),
+            answer: stored.answer.and_then(read),
+            words: stored
+                .words
+                .into_iter()
+                .map(read)
+                .collect::<Option<Vec<String>>>()
+                .unwrap_or_default(),
+            think_times: stored
+                .think_secs
+                .into_iter()
+                .map(Duration::from_secs)
+                .collect(),
+        }
+    }
+}
+
+impl GameRecord {
+    // what versions before the json format wrote, a line each:
+    // <date> <won|lost> <guesses> [seconds taken or -] [answer] [guess,guess,...], obscured
+    // answers and guesses start with a ~
+    fn parse_legacy(line: &str) -> Option<Self> {
+        let mut parts = line.split_whitespace();
+
+        let date = NaiveDate::parse_from_str(parts.next()?, "%Y-%m-%d").ok()?;
+        let won = match parts.next()? {
+            "won" => true,
+            "lost" => false,
+            _ => return None,
+        };
+        let guesses = parts.next()?.parse().ok()?;
+        let duration = parts
+            .next()
+            .and_then(|s| s.parse().ok())
+            .map(Duration::from_secs);
+        let answer = parts.next();
+        let read = |field: &str| {
+            field
+                .strip_prefix('~')
+                .map_or_else(|| Some(field.to_owned()), challenge::unobscure)
+        };
+        let answer = answer.and_then(read);
+        let words = parts
+            .next()
+            .and_then(read)
+            .map(|words| words.split(',').map(str::to_owned).collect())
+            .unwrap_or_default();
+
+        Some(Self {
+            date,
+            won,
+            guesses,
+            duration,
+            answer,
+            words,
+            think_times: Vec::new(),
+        })
+    }
+}
+
+// what past guesses say about how letters get played, from the records that kept their words
+#[derive(Debug, Default)]
+pub struct LetterStats {
+    // letters of each first guess
+    pub openers: HashMap<char, usize>,
+    // letters that turned up yellow
+    pub misplaced: HashMap<char, usize>,
+    greens: usize,
+    guesses: usize,
+}
+
+impl LetterStats {
+    #[allow(clippy::cast_precision_loss)]
+    pub fn greens_per_guess(&self) -> Option<f64> {
+        (self.guesses > 0).then(|| self.greens as f64 / self.guesses as f64)
+    }
+
+    // the few letters counted the most, most first
+    pub fn top(counts: &HashMap<char, usize>, n: usize) -> String {
+        let mut counts = counts.iter().collect::<Vec<(&char, &usize)>>();
+        counts.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+        counts
+            .into_iter()
+            .take(n)
+            .map(|(letter, _)| letter.to_string())
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+// what the stats popup is showing
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum Tab {
+    // totals, the calendar and letters
+    #[default]
+    Overview,
+    Weeks,
+    Months,
+}
+
+impl Tab {
+    const ALL: [Self; 3] = [Self::Overview, Self::Weeks, Self::Months];
+
+    // ←/→ move between tabs, wrapping around
+    pub fn switch(self, code: KeyCode) -> Option<Self> {
+        let index = Self::ALL.iter().position(|&tab| tab == self).unwrap_or(0);
+        let index = match code {
+            KeyCode::Right | KeyCode::Tab => index + 1,
+            KeyCode::Left | KeyCode::BackTab => index + Self::ALL.len() - 1,
+            _ => return None,
+        };
+        Some(Self::ALL[index % Self::ALL.len()])
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Overview => "overview",
+            Self::Weeks => "weeks",
+            Self::Months => "months",
+        }
+    }
+}
+
+// how much time each roll-up covers, weeks start on monday
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Period {
+    Week,
+    Month,
+}
+
+impl Period {
+    // the first day of the one `date` is in
+    fn start(self, date: NaiveDate) -> NaiveDate {
+        match self {
+            Self::Week => date - Days::new(u64::from(date.weekday().num_days_from_monday())),
+            Self::Month => date.with_day(1).unwrap_or(date),
+        }
+    }
+
+    fn previous(self, start: NaiveDate) -> NaiveDate {
+        match self {
+            Self::Week => start - Days::new(7),
+            Self::Month => start - Months::new(1),
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Week => "week",
+            Self::Month => "month",
+        }
+    }
+
+    // "oct 12" for the week starting then, "oct 2026" for the month
+    fn label(self, start: NaiveDate) -> String {
+        let label = match self {
+            Self::Week => start.format("%b %d"),
+            Self::Month => start.format("%b %Y"),
+        };
+        label.to_string().to_lowercase()
+    }
+}
+
+// the games of one week or month added up
+#[derive(Debug, Clone, Default)]
+pub struct RollUp {
+    pub played: usize,
+    pub won: usize,
+    // across the wins
+    pub guesses: usize,
+    pub distribution: [u64; 6],
+}
+
+impl RollUp {
+    fn add(&mut self, record: &GameRecord) {
+        self.played += 1;
+        if !record.won {
+            return;
+        }
+        self.won += 1;
+        self.guesses += usize::from(record.guesses);
+        let index = usize::from(record.guesses).checked_sub(1);
+        if let Some(count) = index.and_then(|i| self.distribution.get_mut(i)) {
+            *count += 1;
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub fn average(&self) -> Option<f64> {
+        (self.won > 0).then(|| self.guesses as f64 / self.won as f64)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Stats {
+    path: PathBuf,
+    records: Vec<GameRecord>,
+}
+
+impl Stats {
+    pub fn load(path: &Path) -> Self {
+        // skip anything unparseable rather than losing every other record
+        let records = match storage::read_versioned(path) {
+            Contents::Versioned(mut stats) => match stats["records"].take() {
+                Value::Array(records) => records
+                    .into_iter()
+                    .filter_map(|record| serde_json::from_value::<StoredRecord>(record).ok())
+                    .map(GameRecord::from)
+                    .collect(),
+                _ => Vec::new(),
+            },
+            Contents::Legacy(contents) => contents
+                .lines()
+                .filter_map(GameRecord::parse_legacy)
+                .collect(),
+            Contents::Missing => Vec::new(),
+        };
+
+        Self {
+            path: path.to_path_buf(),
+            records,
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let records = self
+            .records
+            .iter()
+            .map(StoredRecord::from)
+            .collect::<Vec<StoredRecord>>();
+
+        storage::write_versioned(&self.path, VERSION, &StatsFile { records })
+    }
+
+    pub fn record(&mut self, record: GameRecord) {
+        self.records.push(record);
+    }
+
+    pub fn last(&self) -> Option<&GameRecord> {
+        self.records.last()
+    }
+
+    pub fn records(&self) -> &[GameRecord] {
+        &self.records
+    }
+
+    pub const fn played(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn win_percentage(&self) -> u8 {
+        if self.records.is_empty() {
+            return 0;
+        }
+
+        let wins = self.records.iter().filter(|r| r.won).count();
+
+        #[allow(clippy::cast_possible_truncation)]
+        let percentage = (wins * 100 / self.records.len()) as u8;
+        percentage
+    }
+
+    // runs of wins on back to back days, a loss or a day without a game ends one
+    fn streaks(&self) -> impl Iterator<Item = usize> + '_ {
+        self.records
+            .chunk_by(|a, b| a.won && b.won && (b.date - a.date).num_days() <= 1)
+            .map(|run| if run[0].won { run.len() } else { 0 })
+    }
+
+    // still going if the last win was today, or yesterday with today left to play
+    pub fn current_streak(&self, today: NaiveDate) -> usize {
+        let Some(last) = self.last() else {
+            return 0;
+        };
+        if (today - last.date).num_days() > 1 {
+            return 0;
+        }
+
+        self.streaks().last().unwrap_or(0)
+    }
+
+    pub fn max_streak(&self) -> usize {
+        self.streaks().max().unwrap_or(0)
+    }
+
+    // days since the first game that went without one, not counting today
+    pub fn missed_days(&self, today: NaiveDate) -> usize {
+        let Some(first) = self.records.first() else {
+            return 0;
+        };
+
+        let played = self
+            .records
+            .iter()
+            .map(|r| r.date)
+            .filter(|&date| date < today)
+            .collect::<HashSet<NaiveDate>>();
+        let days = usize::try_from((today - first.date).num_days()).unwrap_or(0);
+        days.saturating_sub(played.len())
+    }
+
+    pub fn best_time(&self) -> Option<Duration> {
+        self.records
+            .iter()
+            .filter(|r| r.won)
+            .filter_map(|r| r.duration)
+            .min()
+    }
+
+    pub fn letters(&self) -> LetterStats {
+        let mut letters = LetterStats::default();
+        for record in &self.records {
+            let Some(answer) = &record.answer else {
+                continue;
+            };
+
+            // rows given up in blitz mode weren't really guessed
+            let words = record
+                .words
+                .iter()
+                .filter(|word| !word.chars().all(|c| c == FORFEITED))
+                .collect::<Vec<&String>>();
+            if let Some(opener) = words.first() {
+                for letter in opener.chars() {
+                    *letters.openers.entry(letter).or_default() += 1;
+                }
+            }
+            for word in words {
+                for (letter, state) in word.chars().zip(score_guess(answer, word)) {
+                    match state {
+                        LetterState::Correct => letters.greens += 1,
+                        LetterState::Present => *letters.misplaced.entry(letter).or_default() += 1,
+                        LetterState::Absent => {}
+                    }
+                }
+                letters.guesses += 1;
+            }
+        }
+
+        letters
+    }
+
+    // every week or month that has a game, by the day it starts
+    fn roll_ups(&self, period: Period) -> BTreeMap<NaiveDate, RollUp> {
+        let mut roll_ups = BTreeMap::<NaiveDate, RollUp>::new();
+        for record in &self.records {
+            roll_ups
+                .entry(period.start(record.date))
+                .or_default()
+                .add(record);
+        }
+        roll_ups
+    }
+
+    // the last `count` weeks or months up to the one `today` is in, oldest first, with the
+    // ones that went without a game left empty
+    pub fn recent(
+        &self,
+        period: Period,
+        today: NaiveDate,
+        count: usize,
+    ) -> Vec<(NaiveDate, RollUp)> {
+        let roll_ups = self.roll_ups(period);
+        let mut recent = Vec::with_capacity(count);
+        let mut start = period.start(today);
+        for _ in 0..count {
+            recent.push((start, roll_ups.get(&start).cloned().unwrap_or_default()));
+            start = period.previous(start);
+        }
+        recent.reverse();
+        recent
+    }
+
+    // the week or month with the fewest guesses per win, the earliest if there's a tie
+    pub fn best(&self, period: Period) -> Option<(NaiveDate, RollUp)> {
+        self.roll_ups(period)
+            .into_iter()
+            .filter(|(_, roll_up)| roll_up.won >= BEST_MIN_WINS)
+            .min_by(|(_, a), (_, b)| {
+                a.average()
+                    .partial_cmp(&b.average())
+                    .unwrap_or(Ordering::Equal)
+            })
+    }
+
+    // number of wins for each guess count, index 0 being a win in one guess
+    pub fn distribution(&self) -> [u64; 6] {
+        let mut distribution = [0; 6];
+        for record in self.records.iter().filter(|r| r.won) {
+            let index = usize::from(record.guesses).checked_sub(1);
+            if let Some(count) = index.and_then(|i| distribution.get_mut(i)) {
+                *count += 1;
+            }
+        }
+
+        distribution
+    }
+}
+
+pub fn render_summary(stats: &Stats, frame: &mut Frame, area: Rect, today: NaiveDate, hint: &str) {
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Length(6),
+            Constraint::Length(2),
+        ])
+        .split(area);
+
+    let summary = format!(
+        "played {}  win % {}  streak {}  max streak {}{}",
+        stats.played(),
+        stats.win_percentage(),
+        stats.current_streak(today),
+        stats.max_streak(),
+        stats
+            .best_time()
+            .map(|t| format!("  best time {}", timer::format(t)))
+            .unwrap_or_default()
+    );
+    let summary = Paragraph::new(Line::from(summary)).centered();
+    frame.render_widget(summary, layout[0]);
+
+    // highlight the bar this game landed in
+    let last_win_guesses = stats
+        .last()
+        .filter(|r| r.won)
+        .map(|r| usize::from(r.guesses));
+
+    let bars = stats
+        .distribution()
+        .iter()
+        .enumerate()
+        .map(|(i, &count)| {
+            let color = if last_win_guesses == Some(i + 1) {
+                Color::LightGreen
+            } else {
+                Color::DarkGray
+            };
+
+            Bar::default()
+                .value(count)
+                .label(Line::from((i + 1).to_string()))
+                .style(Style::default().fg(color))
+                .value_style(Style::default().fg(Color::White).bg(color))
+        })
+        .collect::<Vec<Bar>>();
+
+    let chart_area = Layout::horizontal([Constraint::Max(40)])
+        .flex(ratatui::layout::Flex::Center)
+        .split(layout[1])[0];
+
+    let chart = BarChart::default()
+        .direction(Direction::Horizontal)
+        .bar_width(1)
+        .bar_gap(0)
+        .data(BarGroup::default().bars(&bars));
+    frame.render_widget(chart, chart_area);
+
+    // the hint gets long with everything on, it can spill onto the second line
+    let hint = Paragraph::new(hint)
+        .style(Style::default().fg(Color::DarkGray).dim())
+        .centered()
+        .wrap(Wrap { trim: true });
+    frame.render_widget(hint, layout[2]);
+}
+
+// the stats popup, the overview or how the weeks and months have been going. returns how far
+// it's scrolled, see `help::scrolled`
+pub fn render(
+    stats: &Stats,
+    config: &Config,
+    frame: &mut Frame,
+    today: NaiveDate,
+    tab: Tab,
+    scroll: u16,
+) -> u16 {
+    let mut lines = vec![tabs(tab, config), Line::default()];
+    lines.extend(match tab {
+        Tab::Overview => overview(stats, config, today),
+        Tab::Weeks => trends(stats, config, today, Period::Week),
+        Tab::Months => trends(stats, config, today, Period::Month),
+    });
+
+    let (area, scroll) = help::scrolled(frame.area(), lines.len(), scroll);
+
+    let popup = Paragraph::new(lines).scroll((scroll, 0)).block(
+        Block::bordered()
+            .title(" stats ")
+            .title_bottom(
+                Line::from(format!(
+                    " ←/→ for trends, {} to close ",
+                    config.keys.close.primary()
+                ))
+                .centered(),
+            )
+            .border_style(Style::default().fg(Color::DarkGray))
+            .padding(Padding::horizontal(1)),
+    );
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
+    scroll
+}
+
+fn tabs(selected: Tab, config: &Config) -> Line<'static> {
+    let mut spans = Vec::new();
+    for tab in Tab::ALL {
+        if !spans.is_empty() {
+            spans.push(Span::from(" │ ").dark_gray());
+        }
+        spans.push(if tab == selected {
+            Span::from(tab.name()).fg(config.colors.title).bold()
+        } else {
+            Span::from(tab.name()).dark_gray()
+        });
+    }
+    Line::from(spans).centered()
+}
+
+// totals and a calendar of every day played in the last few months
+fn overview(stats: &Stats, config: &Config, today: NaiveDate) -> Vec<Line<'static>> {
+    let missed = match stats.missed_days(today) {
+        0 => String::from("no days missed"),
+        1 => String::from("1 day missed"),
+        days => format!("{days} days missed"),
+    };
+
+    let mut lines = vec![
+        Line::from(format!(
+            "played {}  win % {}  streak {}  max streak {}",
+            stats.played(),
+            stats.win_percentage(),
+            stats.current_streak(today),
+            stats.max_streak(),
+        )),
+        Line::from(missed).dark_gray(),
+        Line::default(),
+    ];
+    lines.extend(calendar(stats, config, today));
+    lines.push(Line::default());
+    lines.push(Line::from(vec![
+        Span::from("■").fg(config.colors.correct),
+        Span::from(" won  "),
+        Span::from("×").fg(Color::LightRed),
+        Span::from(" lost  "),
+        Span::from("·").fg(Color::DarkGray),
+        Span::from(" no game"),
+    ]));
+    lines.extend(letter_lines(stats, config));
+
+    lines
+}
+
+// "4 played  3 won  avg 3.7", the average being guesses per win
+fn roll_up_summary(roll_up: &RollUp) -> String {
+    let average = roll_up
+        .average()
+        .map(|average| format!("  avg {average:.1}"))
+        .unwrap_or_default();
+    format!("{} played  {} won{average}", roll_up.played, roll_up.won)
+}
+
+// at least a sliver for anything above nothing
+#[allow(clippy::cast_precision_loss)]
+fn bar(value: f64, max: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let width = (value / max * BAR_WIDTH as f64).round() as usize;
+    "█".repeat(width.max(usize::from(value > 0.0)))
+}
+
+// this week or month against the last, guesses per win over the last few and the best one
+fn trends(stats: &Stats, config: &Config, today: NaiveDate, period: Period) -> Vec<Line<'static>> {
+    let recent = stats.recent(period, today, TREND_PERIODS);
+    let (current, previous) = (&recent[recent.len() - 1].1, &recent[recent.len() - 2].1);
+    let name = period.name();
+
+    let mut this = Line::from(format!("this {name}  {}", roll_up_summary(current)));
+    if let (Some(now), Some(then)) = (current.average(), previous.average()) {
+        let change = match now.partial_cmp(&then) {
+            Some(Ordering::Less) => format!(" ({:.1} fewer)", then - now),
+            Some(Ordering::Greater) => format!(" ({:.1} more)", now - then),
+            _ => String::new(),
+        };
+        this.spans.push(Span::from(change).dark_gray());
+    }
+    let mut lines = vec![
+        this,
+        Line::from(format!("last {name}  {}", roll_up_summary(previous))).dark_gray(),
+    ];
+
+    lines.push(Line::default());
+    lines.push(Line::from("guesses per win").dark_gray());
+    let max = recent
+        .iter()
+        .filter_map(|(_, roll_up)| roll_up.average())
+        .fold(1.0, f64::max);
+    for (start, roll_up) in &recent {
+        let label = format!("{:<9}", period.label(*start));
+        lines.push(match roll_up.average() {
+            Some(average) => Line::from(vec![
+                Span::from(label),
+                Span::from(bar(average, max)).fg(config.colors.correct),
+                Span::from(format!(" {average:.1}")),
+            ]),
+            None => Line::from(vec![
+                Span::from(label),
+                Span::from(if roll_up.played == 0 {
+                    "no games"
+                } else {
+                    "no wins"
+                })
+                .dark_gray(),
+            ]),
+        });
+    }
+
+    lines.push(Line::default());
+    lines.push(Line::from(format!("wins by guesses, against last {name}")).dark_gray());
+    let most = current
+        .distribution
+        .iter()
+        .copied()
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    for (i, (&now, &then)) in current
+        .distribution
+        .iter()
+        .zip(&previous.distribution)
+        .enumerate()
+    {
+        let change = match now.cmp(&then) {
+            Ordering::Equal => String::from("="),
+            Ordering::Greater => format!("+{}", now - then),
+            Ordering::Less => format!("-{}", then - now),
+        };
+        #[allow(clippy::cast_precision_loss)]
+        let width = bar(now as f64, most as f64);
+        lines.push(Line::from(vec![
+            Span::from(format!("{}  ", i + 1)),
+            Span::from(width).fg(config.colors.wrong_placement),
+            Span::from(format!(" {now}  ")),
+            Span::from(change).dark_gray(),
+        ]));
+    }
+
+    if let Some((start, best)) = stats.best(period) {
+        lines.push(Line::default());
+        lines.push(Line::from(format!(
+            "best {name}: {}, {:.1} guesses per win over {} wins",
+            period.label(start),
+            best.average().unwrap_or_default(),
+            best.won
+        )));
+    }
+
+    lines
+}
+
+// the openers and yellows as heatmaps over the keyboard, side by side
+fn letter_lines(stats: &Stats, config: &Config) -> Vec<Line<'static>> {
+    let letters = stats.letters();
+    let Some(greens) = letters.greens_per_guess() else {
+        return Vec::new();
+    };
+
+    let gap = "    ";
+    let openers = format!("opens with {}", LetterStats::top(&letters.openers, 3));
+    let mut lines = vec![
+        Line::default(),
+        Line::from(format!(
+            "{openers:<width$}{gap}misplaced {}",
+            LetterStats::top(&letters.misplaced, 3),
+            width = keyboard::HEATMAP_WIDTH
+        )),
+    ];
+    lines.extend(
+        keyboard::heatmap(
+            &letters.openers,
+            config.colors.title,
+            config.keyboard_layout,
+        )
+        .into_iter()
+        .zip(keyboard::heatmap(
+            &letters.misplaced,
+            config.colors.wrong_placement,
+            config.keyboard_layout,
+        ))
+        .map(|(openers, misplaced)| {
+            let mut spans = openers.spans;
+            spans.push(Span::from(gap));
+            spans.extend(misplaced.spans);
+            Line::from(spans)
+        }),
+    );
+    lines.push(Line::from(format!("{greens:.1} greens per guess")).dark_gray());
+
+    lines
+}
+
+// a row per weekday and a column per week like github's contribution graph, ending on this
+// week. marked with symbols as well as colors so it still reads without them
+fn calendar(stats: &Stats, config: &Config, today: NaiveDate) -> Vec<Line<'static>> {
+    let results = stats
+        .records
+        .iter()
+        .map(|r| (r.date, r.won))
+        .collect::<HashMap<NaiveDate, bool>>();
+
+    let this_week = today - Days::new(u64::from(today.weekday().num_days_from_monday()));
+    let first_week = this_week - Days::new((CALENDAR_WEEKS - 1) * 7);
+
+    ["mon", "tue", "wed", "thu", "fri", "sat", "sun"]
+        .iter()
+        .enumerate()
+        .map(|(weekday, label)| {
+            let mut spans = vec![Span::from(format!("{label} ")).dark_gray()];
+            for week in 0..CALENDAR_WEEKS {
+                let date = first_week + Days::new(week * 7 + weekday as u64);
+                let cell = match results.get(&date) {
+                    _ if date > today => Span::from(" "),
+                    Some(true) => Span::from("■").fg(config.colors.correct),
+                    Some(false) => Span::from("×").fg(Color::LightRed),
+                    None => Span::from("·").fg(Color::DarkGray),
+                };
+                spans.push(cell);
+                spans.push(Span::from(" "));
+            }
+
+            Line::from(spans)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, fs};
+
+    fn load(name: &str, contents: &str) -> Vec<GameRecord> {
+        let path = env::temp_dir().join(format!("wordle-tui-{name}-{}", std::process::id()));
+        fs::write(&path, contents).unwrap();
+        let records = Stats::load(&path).records;
+        let _ = fs::remove_file(&path);
+        records
+    }
+
+    #[test]
+    fn stats_written_in_plain_text_by_older_versions_still_load() {
+        let json = load(
+            "plain-stats",
+            r#"{"version": 1, "records": [{"date": "2026-03-14", "won": true, "guesses": 2,
+                "answer": "CRANE", "words": ["SLATE", "CRANE"]}]}"#,
+        );
+        let lines = load(
+            "legacy-stats",
+            "2026-03-14 won 2 40 CRANE SLATE,CRANE\n2026-03-15 lost 6 - ~NCMzIT8\n",
+        );
+
+        for records in [&json, &lines] {
+            assert_eq!(records[0].answer.as_deref(), Some("CRANE"));
+            assert_eq!(records[0].words, ["SLATE", "CRANE"]);
+        }
+        // how streamer mode wrote them
+        assert_eq!(lines[1].answer.as_deref(), Some("CLAES"));
+    }
+
+    #[test]
+    fn answers_and_guesses_are_saved_obscured() {
+        let path =
+            env::temp_dir().join(format!("wordle-tui-obscured-stats-{}", std::process::id()));
+        let mut stats = Stats::load(&path);
+        stats.record(GameRecord {
+            date: NaiveDate::from_ymd_opt(2026, 3, 14).unwrap(),
+            won: true,
+            guesses: 2,
+            duration: None,
+            answer: Some(String::from("CRANE")),
+            words: vec![String::from("SLATE"), String::from("CRANE")],
+            think_times: Vec::new(),
+        });
+        stats.save().unwrap();
+
+        let saved = fs::read_to_string(&path).unwrap();
+        let loaded = Stats::load(&path).records;
+        let _ = fs::remove_file(&path);
+        assert!(
+            !saved.contains("CRANE") && !saved.contains("SLATE"),
+            "{saved}"
+        );
+        assert_eq!(loaded, stats.records);
+    }
+}
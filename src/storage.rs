@@ -0,0 +1,203 @@
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+use serde_json::Value;
+use std::{
+    ffi::OsString,
+    fs::{self, File, OpenOptions},
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{config::Paths, profile};
+
+// where every file used to live, relative to wherever the binary was run from
+const LEGACY_WORD_LIST_CACHE: &str = ".word-list.cache.txt";
+const LEGACY_PLAY_STATE: &str = ".play.state.txt";
+const LEGACY_STATS: &str = ".stats.txt";
+
+pub fn data_dir() -> PathBuf {
+    profile::dir(dirs::data_dir().map_or_else(|| PathBuf::from("."), |dir| dir.join("wordle-tui")))
+}
+
+// `path` with something tacked onto the end of its name, for files kept next to it
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+// where the last good copy of a state file is kept, see `write_versioned`
+fn backup(path: &Path) -> PathBuf {
+    sibling(path, ".bak")
+}
+
+// written out in full next to the file before it's swapped in, so losing power halfway
+// through leaves the old file as it was rather than half of the new one
+pub fn write(path: &Path, contents: impl AsRef<[u8]>) -> Result<()> {
+    write_replacing(path, contents.as_ref(), None)
+}
+
+// like `write`, with whatever was there before moved to `<path><suffix>` rather than lost
+pub fn write_keeping(path: &Path, contents: impl AsRef<[u8]>, suffix: &str) -> Result<()> {
+    write_replacing(path, contents.as_ref(), Some(&sibling(path, suffix)))
+}
+
+// moves whatever was at `path` to `keep` just before the new file takes its place
+fn write_replacing(path: &Path, contents: &[u8], keep: Option<&Path>) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+
+    let temp = sibling(path, ".tmp");
+    let mut file =
+        File::create(&temp).with_context(|| format!("failed to create {}", temp.display()))?;
+    file.write_all(contents)
+        .and_then(|()| file.sync_all())
+        .with_context(|| format!("failed to write {}", temp.display()))?;
+    drop(file);
+
+    if let Some(keep) = keep {
+        match fs::rename(path, keep) {
+            Err(err) if err.kind() != ErrorKind::NotFound => {
+                return Err(err).with_context(|| format!("failed to back up {}", path.display()))
+            }
+            _ => {}
+        }
+    }
+    fs::rename(&temp, path).with_context(|| format!("failed to replace {}", path.display()))
+}
+
+pub fn append_line(path: &Path, line: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("failed to open {}", path.display()))?;
+    writeln!(file, "{line}").with_context(|| format!("failed to write {}", path.display()))
+}
+
+// fnv-1a, which unlike the std hasher is guaranteed to stay the same between releases
+pub fn hash(bytes: impl IntoIterator<Item = u8>) -> u64 {
+    bytes.into_iter().fold(0xCBF2_9CE4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01B3)
+    })
+}
+
+// moves files left in the working directory by older versions into their new home,
+// never overwriting anything that's already there
+pub fn migrate_legacy_files(paths: &Paths) -> Result<()> {
+    for (legacy, target) in [
+        (LEGACY_WORD_LIST_CACHE, &paths.word_list_cache),
+        (LEGACY_PLAY_STATE, &paths.play_state),
+        (LEGACY_STATS, &paths.stats),
+    ] {
+        let legacy = Path::new(legacy);
+        if !legacy.is_file() || target.exists() || target.as_path() == legacy {
+            continue;
+        }
+
+        let contents =
+            fs::read(legacy).with_context(|| format!("failed to read {}", legacy.display()))?;
+        write(target, contents)?;
+        fs::remove_file(legacy)
+            .with_context(|| format!("failed to remove {}", legacy.display()))?;
+    }
+
+    Ok(())
+}
+
+// state files are a json object with the version of their format and a checksum next to the
+// data, which tells them apart from the line based files older versions wrote. the file they
+// replace is kept as a backup, for when a disk or another program mangles the new one anyway
+pub fn write_versioned<T: Serialize>(path: &Path, version: u32, data: &T) -> Result<()> {
+    let serialize = || -> Result<String> {
+        let mut value = serde_json::to_value(data)?;
+        let checksum = checksum(&value);
+        let Value::Object(fields) = &mut value else {
+            bail!("expected an object");
+        };
+        fields.insert(String::from("version"), Value::from(version));
+        fields.insert(String::from("checksum"), Value::from(checksum));
+        Ok(serde_json::to_string_pretty(&value)?)
+    };
+    let contents =
+        serialize().with_context(|| format!("failed to serialize {}", path.display()))?;
+
+    write_replacing(path, contents.as_bytes(), Some(&backup(path)))
+}
+
+// of everything but the version and the checksum itself
+fn checksum(data: &Value) -> String {
+    format!("{:016x}", hash(data.to_string().into_bytes()))
+}
+
+// everything in the file but its version and checksum, none if it's damaged
+fn parse_versioned(contents: &str) -> Option<Value> {
+    let mut value = serde_json::from_str::<Value>(contents).ok()?;
+    let fields = value.as_object_mut()?;
+    // newer versions are still read, their fields this one doesn't know are skipped
+    fields.remove("version")?.as_u64()?;
+    // files from before checksums are trusted as long as they parse
+    if let Some(stored) = fields.remove("checksum") {
+        if stored.as_str() != Some(checksum(&value).as_str()) {
+            return None;
+        }
+    }
+
+    Some(value)
+}
+
+#[derive(Debug)]
+pub enum Contents {
+    Versioned(Value),
+    // from before files had a version, to be parsed the old way
+    Legacy(String),
+    Missing,
+}
+
+// a state file, or its backup if it's missing or damaged. a damaged file with no backup to
+// fall back on is moved aside to `<file>.damaged` rather than overwritten, in case it can
+// still be pieced together by hand
+pub fn read_versioned(path: &Path) -> Contents {
+    let read = |path: &Path| fs::read_to_string(path).ok();
+
+    let Some(contents) = read(path) else {
+        // the moment between backing up the old file and moving the new one in
+        return read(&backup(path))
+            .and_then(|contents| parse_versioned(&contents))
+            .map_or(Contents::Missing, Contents::Versioned);
+    };
+    if let Some(data) = parse_versioned(&contents) {
+        return Contents::Versioned(data);
+    }
+    // older versions never wrote json
+    if !contents.trim_start().starts_with('{') && !contents.trim().is_empty() {
+        return Contents::Legacy(contents);
+    }
+
+    if let Some(data) = read(&backup(path)).and_then(|backup| parse_versioned(&backup)) {
+        return Contents::Versioned(data);
+    }
+    let _ = fs::rename(path, sibling(path, ".damaged"));
+    Contents::Missing
+}
+
+// durations as whole seconds, for `#[serde(with = "storage::seconds")]`
+pub mod seconds {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_secs())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_secs)
+    }
+}
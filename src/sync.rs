@@ -0,0 +1,206 @@
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use ratatui::{
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Clear, Padding, Paragraph},
+    Frame,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    env,
+    sync::mpsc::{self, Receiver, TryRecvError},
+    thread,
+};
+use ureq::{Agent, Request};
+use wordle_tui::wordle_core::MAX_GUESSES;
+
+use crate::{config::Config, help, network, profile};
+
+// a leaderboard server shared with friends, anything that speaks this works:
+//   POST <url>/results         an `Entry` for the day, as json
+//   GET  <url>/results/<date>  every entry for that day, as a json list
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Server {
+    pub url: Option<String>,
+    // sent as a bearer token with every request
+    pub token: Option<String>,
+    // shown to everyone else, the login name otherwise
+    pub name: Option<String>,
+}
+
+impl Server {
+    pub fn name(&self) -> String {
+        self.name
+            .clone()
+            .or_else(|| profile::current().map(str::to_owned))
+            .or_else(|| env::var("USER").ok())
+            .or_else(|| env::var("USERNAME").ok())
+            .unwrap_or_else(|| String::from("anonymous"))
+    }
+}
+
+// just the guess count, nothing that gives the word away
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct Entry {
+    pub name: String,
+    pub won: bool,
+    pub guesses: u8,
+    #[serde(default)]
+    pub hard_mode: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Client {
+    url: String,
+    token: Option<String>,
+    agent: Agent,
+}
+
+impl Client {
+    // none without a url, there's nowhere to sync to
+    pub fn new(server: &Server, agent: &Agent) -> Option<Self> {
+        Some(Self {
+            url: server.url.as_ref()?.trim_end_matches('/').to_owned(),
+            token: server.token.clone(),
+            agent: agent.clone(),
+        })
+    }
+
+    fn authorized(&self, request: Request) -> Request {
+        match &self.token {
+            Some(token) => request.set("Authorization", &format!("Bearer {token}")),
+            None => request,
+        }
+    }
+
+    pub fn push(&self, date: NaiveDate, entry: &Entry) -> Result<()> {
+        let mut body = serde_json::to_value(entry)?;
+        body["date"] = serde_json::json!(date.to_string());
+        network::send_json(
+            &self.authorized(self.agent.post(&format!("{}/results", self.url))),
+            &body,
+        )
+        .context("failed to send the result")?;
+
+        Ok(())
+    }
+
+    pub fn pull(&self, date: NaiveDate) -> Result<Vec<Entry>> {
+        network::call(&self.authorized(self.agent.get(&format!("{}/results/{date}", self.url))))
+            .context("failed to get the leaderboard")?
+            .into_json()
+            .context("the leaderboard isn't a list of results")
+    }
+}
+
+// today's results from everyone, fetched in the background
+#[derive(Debug, Default)]
+pub enum Leaderboard {
+    #[default]
+    NotSynced,
+    Syncing(Receiver<Result<Vec<Entry>, String>>),
+    Synced(Vec<Entry>),
+    Failed(String),
+}
+
+impl Leaderboard {
+    // sends `entry` first if there is one, so it's part of what comes back
+    pub fn sync(client: Client, date: NaiveDate, entry: Option<Entry>) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let result = entry
+                .map_or(Ok(()), |entry| client.push(date, &entry))
+                .and_then(|()| client.pull(date))
+                .map_err(|err| format!("{err:#}"));
+            // the receiver is only gone if the game was already closed
+            let _ = sender.send(result);
+        });
+
+        Self::Syncing(receiver)
+    }
+
+    pub const fn needs_sync(&self) -> bool {
+        matches!(self, Self::NotSynced | Self::Failed(_))
+    }
+
+    // the error, once, if syncing just failed
+    pub fn poll(&mut self) -> Option<String> {
+        let Self::Syncing(receiver) = self else {
+            return None;
+        };
+
+        let result = match receiver.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) => return None,
+            Err(TryRecvError::Disconnected) => Err(String::from("syncing stopped unexpectedly")),
+        };
+        match result {
+            Ok(mut entries) => {
+                // winners first, fewest guesses at the top
+                entries.sort_by_key(|entry| (!entry.won, entry.guesses));
+                *self = Self::Synced(entries);
+                None
+            }
+            Err(err) => {
+                *self = Self::Failed(err.clone());
+                Some(err)
+            }
+        }
+    }
+}
+
+pub fn render(leaderboard: &Leaderboard, name: &str, config: &Config, frame: &mut Frame) {
+    let lines = match leaderboard {
+        Leaderboard::NotSynced | Leaderboard::Syncing(_) => {
+            vec![Line::from("syncing...").dark_gray()]
+        }
+        Leaderboard::Failed(err) => vec![Line::from(err.as_str()).fg(Color::LightRed)],
+        Leaderboard::Synced(entries) if entries.is_empty() => {
+            vec![Line::from("nobody has played today yet").dark_gray()]
+        }
+        Leaderboard::Synced(entries) => entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let guesses = if entry.won {
+                    format!("{}/{MAX_GUESSES}", entry.guesses)
+                } else {
+                    format!("X/{MAX_GUESSES}")
+                };
+                let line = Line::from(vec![
+                    Span::from(format!("{:>2}. ", i + 1)).dark_gray(),
+                    Span::from(format!("{:<32}", entry.name)),
+                    Span::from(guesses),
+                    Span::from(if entry.hard_mode { "*" } else { "" }),
+                ]);
+                if entry.name == name {
+                    line.fg(config.colors.title).bold()
+                } else {
+                    line
+                }
+            })
+            .collect(),
+    };
+
+    #[allow(clippy::cast_possible_truncation)]
+    let area = help::centered(frame.area(), help::WIDTH, lines.len() as u16 + 2);
+
+    let popup = Paragraph::new(lines).block(
+        Block::bordered()
+            .title(" today's leaderboard ")
+            .title_bottom(
+                Line::from(format!(
+                    " * hard mode, {} to close ",
+                    config.keys.close.primary()
+                ))
+                .centered(),
+            )
+            .border_style(Style::default().fg(Color::DarkGray))
+            .padding(Padding::horizontal(1)),
+    );
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
+}
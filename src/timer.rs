@@ -0,0 +1,86 @@
+use std::time::{Duration, Instant};
+
+// counts from the first keypress, carrying over time spent before a resumed game was quit
+#[derive(Debug)]
+pub struct Timer {
+    carried: Duration,
+    started: Option<Instant>,
+    stopped: Option<Duration>,
+}
+
+impl Timer {
+    pub const fn new(carried: Duration) -> Self {
+        Self {
+            carried,
+            started: None,
+            stopped: None,
+        }
+    }
+
+    pub fn start(&mut self) {
+        if self.started.is_none() && self.stopped.is_none() {
+            self.started = Some(Instant::now());
+        }
+    }
+
+    pub fn stop(&mut self) {
+        if self.stopped.is_none() {
+            self.stopped = Some(self.elapsed());
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.stopped.unwrap_or_else(|| {
+            self.carried
+                + self
+                    .started
+                    .map_or(Duration::ZERO, |started| started.elapsed())
+        })
+    }
+}
+
+// m:ss, or h:mm:ss for the truly stuck
+pub fn format(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    }
+}
+
+// how long is left for the row being guessed in blitz mode, counted again for every row
+#[derive(Debug)]
+pub struct Deadline {
+    limit: Duration,
+    ends: Option<Instant>,
+}
+
+impl Deadline {
+    pub const fn new(limit: Duration) -> Self {
+        Self { limit, ends: None }
+    }
+
+    // does nothing while a row is already being counted down
+    pub fn start(&mut self) {
+        if self.ends.is_none() {
+            self.ends = Some(Instant::now() + self.limit);
+        }
+    }
+
+    pub const fn stop(&mut self) {
+        self.ends = None;
+    }
+
+    pub fn remaining(&self) -> Option<Duration> {
+        self.ends
+            .map(|ends| ends.saturating_duration_since(Instant::now()))
+    }
+
+    pub fn expired(&self) -> bool {
+        self.remaining()
+            .is_some_and(|remaining| remaining.is_zero())
+    }
+}
@@ -0,0 +1,99 @@
+use anyhow::Result;
+use crossterm::{
+    event::{DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture},
+    execute,
+    terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::backend::CrosstermBackend;
+use std::{
+    io::{self, Stdout},
+    ops::{Deref, DerefMut},
+    panic,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Once,
+    },
+};
+
+use crate::{
+    color_support::{ColorBackend, ColorSupport},
+    notify,
+};
+
+// whether the terminal is currently in raw mode on the alternate screen. restoring it twice
+// would jump the cursor back over whatever was printed in between, so the panic hook, early
+// returns and the normal exit all go through this
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+static PANIC_HOOK: Once = Once::new();
+
+pub type Terminal = ratatui::Terminal<ColorBackend<CrosstermBackend<Stdout>>>;
+
+// the terminal while the game has it, handed back however the game ends
+#[derive(Debug)]
+pub struct Tui {
+    terminal: Terminal,
+}
+
+impl Tui {
+    pub fn init(mouse: bool, colors: ColorSupport) -> Result<Self> {
+        PANIC_HOOK.call_once(|| {
+            let hook = panic::take_hook();
+            panic::set_hook(Box::new(move |info| {
+                restore();
+                hook(info);
+            }));
+        });
+
+        ACTIVE.store(true, Ordering::SeqCst);
+        terminal::enable_raw_mode()?;
+        // terminals that don't support it just send the pasted text as key presses
+        execute!(io::stdout(), EnterAlternateScreen, EnableBracketedPaste)?;
+        notify::save_title();
+        if mouse {
+            execute!(io::stdout(), EnableMouseCapture)?;
+        }
+
+        Ok(Self {
+            terminal: Terminal::new(ColorBackend::new(
+                CrosstermBackend::new(io::stdout()),
+                colors,
+            ))?,
+        })
+    }
+}
+
+impl Deref for Tui {
+    type Target = Terminal;
+
+    fn deref(&self) -> &Self::Target {
+        &self.terminal
+    }
+}
+
+impl DerefMut for Tui {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.terminal
+    }
+}
+
+impl Drop for Tui {
+    fn drop(&mut self) {
+        restore();
+    }
+}
+
+pub fn restore() {
+    if !ACTIVE.swap(false, Ordering::SeqCst) {
+        return;
+    }
+
+    // raw mode first, it has more side effects than the alternate screen
+    let _ = terminal::disable_raw_mode();
+    notify::restore_title();
+    let _ = execute!(
+        io::stdout(),
+        DisableMouseCapture,
+        DisableBracketedPaste,
+        LeaveAlternateScreen
+    );
+}
@@ -0,0 +1,161 @@
+use anyhow::{bail, Context, Result};
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::ErrorKind,
+    iter::Peekable,
+    path::{Path, PathBuf},
+};
+
+use crate::{config::Config, storage};
+
+// bumped whenever a file's contents stop meaning the same thing, new files just get skipped by
+// older versions
+const VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Command {
+    Export(PathBuf),
+    Import(PathBuf),
+}
+
+impl Command {
+    // `export-data <file>` or `import-data <file>`, with the subcommand already taken off
+    pub fn parse(
+        name: &str,
+        raw_args: &mut Peekable<impl Iterator<Item = String>>,
+    ) -> Result<Self> {
+        let path = raw_args
+            .next_if(|arg| !arg.starts_with("--"))
+            .map(PathBuf::from)
+            .with_context(|| format!("{name} needs a file"))?;
+        Ok(if name == "import-data" {
+            Self::Import(path)
+        } else {
+            Self::Export(path)
+        })
+    }
+}
+
+// everything someone would miss after reinstalling, in one json file. files are kept exactly
+// as they were, so state files still match their checksums once they're put back
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+struct Archive {
+    version: u32,
+    // only for telling backups apart
+    exported: String,
+    files: BTreeMap<String, String>,
+}
+
+// what goes in the archive, by the name it's kept under. the history is part of the stats
+fn files(config: &Config, config_path: Option<&Path>) -> Vec<(&'static str, PathBuf)> {
+    let paths = &config.paths;
+    let mut files = vec![
+        ("stats", paths.stats.clone()),
+        ("achievements", paths.achievements.clone()),
+        ("play_state", paths.play_state.clone()),
+        ("word_list_extensions", paths.word_list_extensions.clone()),
+    ];
+    if let Some(path) = config_path {
+        files.insert(0, ("config", path.to_path_buf()));
+    }
+    files
+}
+
+fn read(path: &Path) -> Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display())),
+    }
+}
+
+// `config_path` is --config, if it was given
+pub fn run(command: &Command, config: &Config, config_path: Option<&Path>) -> Result<()> {
+    let config_path = config_path
+        .map(Path::to_path_buf)
+        .or_else(Config::default_path);
+    match command {
+        Command::Export(path) => export(path, config, config_path.as_deref()),
+        Command::Import(path) => import(path, config, config_path.as_deref()),
+    }
+}
+
+fn export(path: &Path, config: &Config, config_path: Option<&Path>) -> Result<()> {
+    let mut archive = Archive {
+        version: VERSION,
+        exported: Local::now().format("%Y-%m-%d %H:%M").to_string(),
+        files: BTreeMap::new(),
+    };
+    for (name, file) in files(config, config_path) {
+        if let Some(contents) = read(&file)? {
+            archive.files.insert(name.to_owned(), contents);
+        }
+    }
+    if archive.files.is_empty() {
+        bail!("there's nothing to export yet");
+    }
+
+    storage::write(path, serde_json::to_string_pretty(&archive)? + "\n")?;
+    println!(
+        "exported {} to {}",
+        archive
+            .files
+            .keys()
+            .cloned()
+            .collect::<Vec<String>>()
+            .join(", "),
+        path.display()
+    );
+    Ok(())
+}
+
+// files already here are kept next to the imported ones as `<file>.before-import`, in case
+// the wrong backup was picked
+fn import(path: &Path, config: &Config, config_path: Option<&Path>) -> Result<()> {
+    let contents =
+        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let archive = serde_json::from_str::<Archive>(&contents)
+        .ok()
+        .filter(|archive| archive.version > 0)
+        .with_context(|| format!("{} isn't a wordle-tui export", path.display()))?;
+    if archive.version > VERSION {
+        bail!(
+            "{} was exported by a newer version of wordle-tui",
+            path.display()
+        );
+    }
+
+    let mut imported = Vec::new();
+    for (name, file) in files(config, config_path) {
+        let Some(contents) = archive.files.get(name) else {
+            continue;
+        };
+        storage::write_keeping(&file, contents, ".before-import")?;
+        imported.push(name);
+    }
+    if imported.is_empty() {
+        bail!("{} has nothing in it to import", path.display());
+    }
+
+    println!(
+        "imported {} from the export made {}",
+        imported.join(", "),
+        archive.exported
+    );
+    // the data went where this machine keeps it, which the imported config might not agree on
+    let moved = config_path
+        .filter(|_| imported.contains(&"config"))
+        .and_then(|path| Config::load(Some(path)).ok())
+        .is_some_and(|imported| imported.paths != config.paths);
+    if moved {
+        println!(
+            "the imported config keeps its files somewhere else, move them there or change its \
+             [paths]"
+        );
+    }
+    Ok(())
+}
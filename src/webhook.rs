@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::{
+    sync::mpsc::{self, Receiver, TryRecvError},
+    thread,
+};
+use ureq::Agent;
+
+use crate::network;
+
+// what the webhook on the other end expects
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Format {
+    #[default]
+    Discord,
+    // mattermost takes the same thing
+    Slack,
+    // the share text along with everything --export-json writes, for anything custom
+    Json,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Webhook {
+    pub url: Option<String>,
+    pub format: Format,
+    // ask before posting rather than sending every result straight away
+    pub confirm: bool,
+}
+
+impl Default for Webhook {
+    fn default() -> Self {
+        Self {
+            url: None,
+            format: Format::default(),
+            confirm: true,
+        }
+    }
+}
+
+fn payload(format: Format, share: &str, record: &Value) -> Value {
+    match format {
+        Format::Discord => json!({ "content": share }),
+        Format::Slack => json!({ "text": share }),
+        Format::Json => json!({ "share": share, "game": record }),
+    }
+}
+
+// where posting a finished game's result is at
+#[derive(Debug, Default)]
+pub enum Status {
+    #[default]
+    Off,
+    Asking,
+    Posting(Receiver<Result<(), String>>),
+}
+
+impl Status {
+    pub const fn is_asking(&self) -> bool {
+        matches!(self, Self::Asking)
+    }
+
+    // sent in the background so the results stay responsive, see `poll` for how it went
+    pub fn post(webhook: &Webhook, agent: &Agent, share: &str, record: &Value) -> Self {
+        let Some(url) = webhook.url.clone() else {
+            return Self::Off;
+        };
+
+        let (sender, receiver) = mpsc::channel();
+        let agent = agent.clone();
+        let payload = payload(webhook.format, share, record);
+        thread::spawn(move || {
+            let result = network::send_json(&agent.post(&url), &payload)
+                .map(|_| ())
+                .map_err(|err| err.to_string());
+            // the receiver is only gone if the game was already closed
+            let _ = sender.send(result);
+        });
+
+        Self::Posting(receiver)
+    }
+
+    // how the post went, once it's done
+    pub fn poll(&mut self) -> Option<Result<(), String>> {
+        let Self::Posting(receiver) = self else {
+            return None;
+        };
+
+        let result = match receiver.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) => return None,
+            Err(TryRecvError::Disconnected) => Err(String::from("posting stopped unexpectedly")),
+        };
+        *self = Self::Off;
+        Some(result)
+    }
+}
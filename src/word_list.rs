@@ -0,0 +1,408 @@
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use ureq::Agent;
+use wordle_tui::wordle_core::WORD_LENGTH;
+
+use crate::{
+    network::{self, Fetched, Validators},
+    offline, storage,
+};
+
+const NYT_WORDLE_PAGE: &str = "https://www.nytimes.com/games/wordle/index.html";
+// fingerprinted, so it changes whenever the nyt ships a new bundle
+const NYT_WORD_LIST_URL: &str =
+    "https://www.nytimes.com/games-assets/v2/9673.7e73cdd39fb6121fa17d.js";
+const GITHUB_WORD_LIST_URL: &str =
+    "https://raw.githubusercontent.com/tabatkins/wordle-list/main/words";
+// anything smaller than this is some other array that happens to hold a few words
+const MIN_FETCHED_WORDS: usize = 1000;
+// where a cache's answers start, after every accepted word
+const ANSWERS_HEADER: &str = "# answers";
+
+// where guesses are checked against, written as "nyt", "bundled", a url or a file path
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum WordListSource {
+    #[default]
+    Nyt,
+    Bundled,
+    Url(String),
+    File(PathBuf),
+}
+
+impl WordListSource {
+    // what to play with when the network is off limits
+    pub fn offline(&self) -> Self {
+        match self {
+            Self::Nyt => Self::Bundled,
+            other => other.clone(),
+        }
+    }
+
+    // downloaded lists each get their own cache so switching between them doesn't mix words,
+    // local ones aren't cached at all so edits show up straight away
+    pub fn cache_path(&self, nyt_cache: &Path) -> Option<PathBuf> {
+        match self {
+            Self::Nyt => Some(nyt_cache.to_path_buf()),
+            Self::Url(url) => {
+                let name = url
+                    .trim_start_matches("https://")
+                    .trim_start_matches("http://")
+                    .chars()
+                    .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+                    .collect::<String>();
+
+                Some(nyt_cache.with_file_name(format!("word-list.{name}.cache.txt")))
+            }
+            Self::Bundled | Self::File(_) => None,
+        }
+    }
+
+    fn url(&self) -> Option<&str> {
+        match self {
+            Self::Nyt => Some(NYT_WORD_LIST_URL),
+            Self::Url(url) => Some(url),
+            Self::Bundled | Self::File(_) => None,
+        }
+    }
+
+    // the whole list, however it's got, for when there isn't a cached copy to compare with
+    pub fn fetch_words(&self, agent: &Agent) -> Result<Words> {
+        match self.fetch(agent, None)? {
+            WordList::Changed(words, _) => Ok(words),
+            WordList::Unchanged => bail!("the word list server had nothing to send"),
+        }
+    }
+
+    // reads or downloads the list as is, caching and retrying is up to the caller. a download
+    // the `cached` copy is still the same as isn't sent again
+    pub fn fetch(&self, agent: &Agent, cached: Option<&Validators>) -> Result<WordList> {
+        match self {
+            Self::Nyt => fetch_nyt(agent, cached),
+            Self::Bundled => Ok(WordList::Changed(
+                Words::accepted(offline::word_list()),
+                None,
+            )),
+            Self::Url(url) => {
+                let fetched = network::get_if_changed(agent, url, cached)
+                    .with_context(|| format!("failed to fetch word list from {url}"))?;
+
+                WordList::from_fetched(fetched, |contents| Some(Words::accepted(parse(&contents))))
+                    .context("no words in the download")
+            }
+            Self::File(path) => {
+                let contents = fs::read_to_string(path)
+                    .with_context(|| format!("failed to read word list {}", path.display()))?;
+
+                Ok(WordList::Changed(Words::accepted(parse(&contents)), None))
+            }
+        }
+    }
+}
+
+impl From<String> for WordListSource {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "nyt" => Self::Nyt,
+            "bundled" => Self::Bundled,
+            url if url.starts_with("https://") || url.starts_with("http://") => Self::Url(s),
+            _ => Self::File(s.into()),
+        }
+    }
+}
+
+impl From<WordListSource> for String {
+    fn from(source: WordListSource) -> Self {
+        match source {
+            WordListSource::Nyt => Self::from("nyt"),
+            WordListSource::Bundled => Self::from("bundled"),
+            WordListSource::Url(url) => url,
+            WordListSource::File(path) => path.display().to_string(),
+        }
+    }
+}
+
+// what a list accepts as guesses, and which of those it picks its answers from if it says.
+// the nyt's bundle keeps the two apart, every other list leaves `answers` empty
+#[derive(Debug, Clone, Default)]
+pub struct Words {
+    pub accepted: HashSet<String>,
+    pub answers: HashSet<String>,
+}
+
+impl Words {
+    pub fn accepted(accepted: HashSet<String>) -> Self {
+        Self {
+            accepted,
+            answers: HashSet::new(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum WordList {
+    // along with what to check it hasn't changed with next time, if it was downloaded
+    Changed(Words, Option<Validators>),
+    // the cached copy is still what's there
+    Unchanged,
+}
+
+impl WordList {
+    // none if there's no list in what was downloaded
+    fn from_fetched(fetched: Fetched, words: impl FnOnce(String) -> Option<Words>) -> Option<Self> {
+        match fetched {
+            Fetched::Changed(contents, validators) => {
+                Some(Self::Changed(words(contents)?, Some(validators)))
+            }
+            Fetched::Unchanged => Some(Self::Unchanged),
+        }
+    }
+}
+
+// one word per line, anything that isn't a word is skipped. words of every length are kept
+// so one list (and its cache) can serve all of them
+pub fn parse(contents: &str) -> HashSet<String> {
+    contents
+        .lines()
+        .map(|line| line.trim().to_uppercase())
+        .filter(|word| !word.is_empty() && word.chars().all(char::is_alphabetic))
+        .collect()
+}
+
+// just the words that can be played at this length
+pub fn with_length(mut words: Words, length: usize) -> Result<Words> {
+    words.accepted.retain(|word| word.chars().count() == length);
+    words.answers.retain(|word| word.chars().count() == length);
+    if words.accepted.is_empty() {
+        bail!("the word list has no {length} letter words");
+    }
+
+    Ok(words)
+}
+
+// a downloaded list along with where and when it came from, so it can be refreshed once it's
+// old or the url it came from has changed
+#[derive(Debug)]
+pub struct Cache {
+    fetched_at: Option<DateTime<Utc>>,
+    url_hash: Option<u64>,
+    validators: Option<Validators>,
+    pub words: Words,
+}
+
+impl Cache {
+    // # fetched <rfc 3339 timestamp> from <url hash>
+    // # asset <url the words were downloaded from>
+    // # etag <etag>
+    // # last-modified <http date>
+    // <words, one per line>
+    // # answers
+    // <the answers among them, one per line>
+    // everything but the first line is optional, and skipped by older versions with the rest
+    // of what isn't a word
+    pub fn load(path: &Path) -> Option<Self> {
+        let contents = fs::read_to_string(path).ok()?;
+
+        // caches from older versions have no header, so they're treated as stale
+        let header = contents
+            .lines()
+            .next()
+            .and_then(|line| line.strip_prefix("# fetched "))
+            .and_then(|header| header.split_once(" from "));
+        let field = |name: &str| {
+            contents
+                .lines()
+                .take_while(|line| line.starts_with('#'))
+                .find_map(|line| line.strip_prefix(&format!("# {name} ")))
+                .map(str::to_owned)
+        };
+
+        Some(Self {
+            fetched_at: header
+                .and_then(|(fetched_at, _)| DateTime::parse_from_rfc3339(fetched_at).ok())
+                .map(|fetched_at| fetched_at.with_timezone(&Utc)),
+            url_hash: header.and_then(|(_, url_hash)| u64::from_str_radix(url_hash, 16).ok()),
+            validators: field("asset").map(|url| Validators {
+                url,
+                etag: field("etag"),
+                last_modified: field("last-modified"),
+            }),
+            words: parse_cached(&contents),
+        })
+    }
+
+    pub fn save(
+        path: &Path,
+        source: &WordListSource,
+        words: &Words,
+        validators: Option<&Validators>,
+    ) -> Result<()> {
+        let sorted = |words: &HashSet<String>| {
+            let mut sorted = words.iter().map(String::as_str).collect::<Vec<&str>>();
+            sorted.sort_unstable();
+            sorted.join("\n")
+        };
+
+        let mut header = vec![format!(
+            "# fetched {} from {:016x}",
+            Utc::now().to_rfc3339(),
+            url_hash(source)
+        )];
+        if let Some(validators) = validators {
+            header.push(format!("# asset {}", validators.url));
+            for (name, value) in [
+                ("etag", &validators.etag),
+                ("last-modified", &validators.last_modified),
+            ] {
+                if let Some(value) = value {
+                    header.push(format!("# {name} {value}"));
+                }
+            }
+        }
+
+        let mut contents = format!("{}\n{}", header.join("\n"), sorted(&words.accepted));
+        if !words.answers.is_empty() {
+            contents = format!("{contents}\n{ANSWERS_HEADER}\n{}", sorted(&words.answers));
+        }
+        storage::write(path, contents)
+    }
+
+    // what to ask the server whether it's changed with, if it's still for this source
+    pub fn validators(&self, source: &WordListSource) -> Option<&Validators> {
+        self.validators
+            .as_ref()
+            .filter(|_| self.url_hash == Some(url_hash(source)))
+    }
+
+    pub fn is_fresh(&self, source: &WordListSource, max_age: Duration) -> bool {
+        let young_enough = self.fetched_at.is_some_and(|fetched_at| {
+            (Utc::now() - fetched_at)
+                .to_std()
+                .is_ok_and(|age| age < max_age)
+        });
+
+        young_enough && self.url_hash == Some(url_hash(source))
+    }
+}
+
+// the answers are in the accepted words too, so older caches without them read the same
+fn parse_cached(contents: &str) -> Words {
+    let (accepted, answers) = contents
+        .split_once(&format!("\n{ANSWERS_HEADER}\n"))
+        .unwrap_or((contents, ""));
+
+    let answers = parse(answers);
+    let mut accepted = parse(accepted);
+    accepted.extend(answers.iter().cloned());
+    Words { accepted, answers }
+}
+
+fn url_hash(source: &WordListSource) -> u64 {
+    storage::hash(source.url().unwrap_or_default().bytes())
+}
+
+type Strategy = fn(&Agent, Option<&Validators>) -> Result<WordList>;
+
+// tried in order until one of them turns up a list, the bundled list is the last resort but
+// that's up to the caller so it never ends up cached as if it had been downloaded
+fn fetch_nyt(agent: &Agent, cached: Option<&Validators>) -> Result<WordList> {
+    let strategies: [(&str, Strategy); 3] = [
+        ("current game bundle", fetch_current_bundle),
+        ("pinned game bundle", |agent, cached| {
+            fetch_bundle(agent, NYT_WORD_LIST_URL, cached)
+        }),
+        ("github word list", fetch_github_list),
+    ];
+
+    let mut failures = Vec::new();
+    for (name, strategy) in strategies {
+        match strategy(agent, cached) {
+            Ok(words) => return Ok(words),
+            Err(err) => failures.push(format!("{name}: {err:#}")),
+        }
+    }
+
+    bail!("failed to fetch word list ({})", failures.join(", "))
+}
+
+// the bundle name is fingerprinted, so find whatever the game page currently loads
+fn fetch_current_bundle(agent: &Agent, cached: Option<&Validators>) -> Result<WordList> {
+    let page = network::get_string(agent, NYT_WORDLE_PAGE)?;
+
+    let scripts = page
+        .split("<script")
+        .skip(1)
+        .filter_map(|tag| tag.split_once("src=\"")?.1.split_once('"'))
+        .map(|(src, _)| src)
+        .filter(|src| src.contains(".js"))
+        .map(|src| {
+            if src.starts_with("//") {
+                format!("https:{src}")
+            } else if src.starts_with('/') {
+                format!("https://www.nytimes.com{src}")
+            } else {
+                src.to_owned()
+            }
+        });
+
+    for script in scripts {
+        if let Ok(words) = fetch_bundle(agent, &script, cached) {
+            return Ok(words);
+        }
+    }
+
+    bail!("no script on the game page has a word list")
+}
+
+fn fetch_bundle(agent: &Agent, url: &str, cached: Option<&Validators>) -> Result<WordList> {
+    let fetched = network::get_if_changed(agent, url, cached)?;
+    WordList::from_fetched(fetched, |bundle| extract_words(&bundle))
+        .context("no word list in the bundle")
+}
+
+fn fetch_github_list(agent: &Agent, cached: Option<&Validators>) -> Result<WordList> {
+    let fetched = network::get_if_changed(agent, GITHUB_WORD_LIST_URL, cached)?;
+    WordList::from_fetched(fetched, |contents| Some(Words::accepted(parse(&contents))))
+        .context("no words in the github list")
+}
+
+// the words are big array literals somewhere in the minified bundle, so look for arrays of
+// quoted five letter words rather than relying on whatever they're named today. the possible
+// answers have been kept in an array apart from the rest of the guesses, the smaller of the
+// two, while there were two
+fn extract_words(js: &str) -> Option<Words> {
+    let mut arrays = js
+        .split('[')
+        .skip(1)
+        .filter_map(|candidate| candidate.split_once(']'))
+        .filter_map(|(array, _)| {
+            array
+                .split(',')
+                .map(|item| {
+                    let word = item
+                        .trim()
+                        .strip_prefix(['"', '\''])?
+                        .strip_suffix(['"', '\''])?;
+                    (word.len() == WORD_LENGTH && word.bytes().all(|b| b.is_ascii_lowercase()))
+                        .then_some(word)
+                })
+                .collect::<Option<Vec<&str>>>()
+        })
+        .filter(|words| words.len() >= MIN_FETCHED_WORDS)
+        .collect::<Vec<Vec<&str>>>();
+    arrays.sort_by_key(Vec::len);
+
+    let answers = match arrays.as_slice() {
+        [answers, _, ..] => parse(&answers.join("\n")),
+        _ => HashSet::new(),
+    };
+    let accepted = parse(&arrays.concat().join("\n"));
+    (!accepted.is_empty()).then_some(Words { accepted, answers })
+}
@@ -0,0 +1,735 @@
+//! The rules of the game, free of any terminal or network concerns.
+
+use fastrand::Rng;
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// The classic length, anything else has to come from a word list of its own.
+pub const WORD_LENGTH: usize = 5;
+pub const MAX_GUESSES: usize = 6;
+/// Fills a row that was given up on, it never matches a letter of the solution.
+pub const FORFEITED: char = '-';
+
+/// Ordered by how much a tile pins its letter down, so the most telling of
+/// several can be picked with `max`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum LetterState {
+    Absent,
+    Present,
+    Correct,
+}
+
+/// Scores `guess` against `solution`, both expected to be uppercase and the
+/// same length. There's a state for every letter of the solution.
+///
+/// Greens are handed out first and use up their letter in the solution, then
+/// whatever is left of each letter goes to yellows from left to right. Any
+/// copies of a letter beyond that are absent, just like the official game.
+#[must_use]
+pub fn score_guess(solution: &str, guess: &str) -> Vec<LetterState> {
+    let solution = solution.chars().collect::<Vec<char>>();
+    let guess = guess.chars().collect::<Vec<char>>();
+
+    let mut states = vec![LetterState::Absent; solution.len()];
+    let mut unmatched = HashMap::<char, usize>::new();
+
+    for (index, state) in states.iter_mut().enumerate() {
+        match (solution.get(index), guess.get(index)) {
+            (Some(s), Some(g)) if s == g => *state = LetterState::Correct,
+            (Some(&s), _) => *unmatched.entry(s).or_default() += 1,
+            (None, _) => {}
+        }
+    }
+
+    for (index, state) in states.iter_mut().enumerate() {
+        if *state == LetterState::Correct {
+            continue;
+        }
+
+        let Some(remaining) = guess.get(index).and_then(|g| unmatched.get_mut(g)) else {
+            continue;
+        };
+        if *remaining > 0 {
+            *remaining -= 1;
+            *state = LetterState::Present;
+        }
+    }
+
+    states
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Guess {
+    pub word: String,
+    pub states: Vec<LetterState>,
+}
+
+impl Guess {
+    pub fn letters(&self) -> impl Iterator<Item = (char, LetterState)> + '_ {
+        self.word.chars().zip(self.states.iter().copied())
+    }
+
+    #[must_use]
+    pub fn is_correct(&self) -> bool {
+        self.states.iter().all(|&s| s == LetterState::Correct)
+    }
+
+    #[must_use]
+    pub fn is_forfeited(&self) -> bool {
+        self.word.chars().all(|c| c == FORFEITED)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum GuessError {
+    NotEnoughLetters,
+    NotInWordList,
+    /// Hard mode is on and the guess ignores something already revealed.
+    MissingHints,
+    GameOver,
+}
+
+impl Display for GuessError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::NotEnoughLetters => "not enough letters",
+            Self::NotInWordList => "not in word list",
+            Self::MissingHints => "every revealed hint must be used",
+            Self::GameOver => "the game is already over",
+        })
+    }
+}
+
+impl Error for GuessError {}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum GameStatus {
+    InProgress,
+    Won,
+    Lost,
+}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    solution: String,
+    guesses: Vec<Guess>,
+    hard_mode: bool,
+    max_guesses: usize,
+}
+
+impl Game {
+    #[must_use]
+    pub fn new(solution: &str, hard_mode: bool) -> Self {
+        Self {
+            solution: solution.to_uppercase(),
+            guesses: Vec::new(),
+            hard_mode,
+            max_guesses: MAX_GUESSES,
+        }
+    }
+
+    /// Allows more (or fewer) than [`MAX_GUESSES`] guesses, for variants that
+    /// need the extra room.
+    #[must_use]
+    pub const fn with_max_guesses(mut self, max_guesses: usize) -> Self {
+        self.max_guesses = max_guesses;
+        self
+    }
+
+    /// Rebuilds a game from words that were already accepted when they were
+    /// first played, so they aren't validated again.
+    ///
+    /// `max_guesses` is the limit the game was played with, the words past
+    /// [`MAX_GUESSES`] would be cut off otherwise.
+    #[must_use]
+    pub fn restore(solution: &str, hard_mode: bool, max_guesses: usize, words: &[String]) -> Self {
+        let mut game = Self::new(solution, hard_mode).with_max_guesses(max_guesses);
+
+        for word in words {
+            if game.status() != GameStatus::InProgress {
+                break;
+            }
+
+            let word = word.to_uppercase();
+            let states = score_guess(&game.solution, &word);
+            game.guesses.push(Guess { word, states });
+        }
+
+        game
+    }
+
+    #[must_use]
+    pub fn solution(&self) -> &str {
+        &self.solution
+    }
+
+    /// How many letters every guess needs, taken from the solution.
+    #[must_use]
+    pub fn word_length(&self) -> usize {
+        self.solution.chars().count()
+    }
+
+    #[must_use]
+    pub fn guesses(&self) -> &[Guess] {
+        &self.guesses
+    }
+
+    #[must_use]
+    pub const fn max_guesses(&self) -> usize {
+        self.max_guesses
+    }
+
+    #[must_use]
+    pub const fn hard_mode(&self) -> bool {
+        self.hard_mode
+    }
+
+    /// Hard mode can only be changed before any hints are revealed, returns
+    /// whether the change was allowed.
+    pub const fn set_hard_mode(&mut self, hard_mode: bool) -> bool {
+        if !self.guesses.is_empty() {
+            return false;
+        }
+
+        self.hard_mode = hard_mode;
+        true
+    }
+
+    #[must_use]
+    pub fn status(&self) -> GameStatus {
+        if self.guesses.last().is_some_and(Guess::is_correct) {
+            GameStatus::Won
+        } else if self.guesses.len() >= self.max_guesses {
+            GameStatus::Lost
+        } else {
+            GameStatus::InProgress
+        }
+    }
+
+    /// Scores and records `guess` if it's a playable word.
+    ///
+    /// # Errors
+    ///
+    /// Returns why the guess was rejected, in which case nothing is recorded.
+    pub fn submit(
+        &mut self,
+        guess: &str,
+        word_list: &HashSet<String>,
+    ) -> Result<&Guess, GuessError> {
+        let guess = guess.to_uppercase();
+        self.check(&guess, word_list)?;
+
+        let states = score_guess(&self.solution, &guess);
+        self.guesses.push(Guess {
+            word: guess,
+            states,
+        });
+
+        Ok(&self.guesses[self.guesses.len() - 1])
+    }
+
+    /// Absurdle's rules, where the solution isn't settled until it has to be.
+    /// Every word in `answers` that fits the guesses so far is grouped by the
+    /// colors `guess` would get against it, and the solution moves to the
+    /// biggest group, the one revealing the least if there's a tie. Earlier
+    /// guesses score the same against any word in it, so they stand as they
+    /// are. It only gives in once the guess is the last word left.
+    ///
+    /// # Errors
+    ///
+    /// Returns why the guess was rejected, like [`Game::submit`].
+    pub fn evade(
+        &mut self,
+        guess: &str,
+        word_list: &HashSet<String>,
+        answers: &HashSet<String>,
+    ) -> Result<&Guess, GuessError> {
+        let guess = guess.to_uppercase();
+        self.check(&guess, word_list)?;
+
+        let mut remaining = answers
+            .iter()
+            .map(|word| word.to_uppercase())
+            .filter(|word| word != &self.solution)
+            .filter(|word| {
+                self.guesses
+                    .iter()
+                    .all(|previous| score_guess(word, &previous.word) == previous.states)
+            })
+            .collect::<Vec<String>>();
+        remaining.push(self.solution.clone());
+        // so the same guesses are always dodged the same way
+        remaining.sort_unstable();
+
+        let mut groups = HashMap::<Vec<LetterState>, Vec<String>>::new();
+        for word in remaining {
+            groups
+                .entry(score_guess(&word, &guess))
+                .or_default()
+                .push(word);
+        }
+        let revealed = |states: &[LetterState]| {
+            let count = |wanted| states.iter().filter(|&&state| state == wanted).count();
+            (count(LetterState::Correct), count(LetterState::Present))
+        };
+        if let Some((_, words)) = groups.into_iter().min_by(|(a, a_words), (b, b_words)| {
+            b_words
+                .len()
+                .cmp(&a_words.len())
+                .then_with(|| revealed(a).cmp(&revealed(b)))
+                .then_with(|| a.cmp(b))
+        }) {
+            self.solution.clone_from(&words[0]);
+        }
+
+        self.submit(&guess, word_list)
+    }
+
+    /// Gives up the current row, which still uses up a guess but reveals
+    /// nothing. Saved and restored like any other guess.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GuessError::GameOver`] if there's no row left to give up.
+    pub fn forfeit(&mut self) -> Result<&Guess, GuessError> {
+        if self.status() != GameStatus::InProgress {
+            return Err(GuessError::GameOver);
+        }
+
+        let word = FORFEITED.to_string().repeat(self.word_length());
+        let states = score_guess(&self.solution, &word);
+        self.guesses.push(Guess { word, states });
+
+        Ok(&self.guesses[self.guesses.len() - 1])
+    }
+
+    /// Whether [`Game::submit`] would accept `guess`, without recording it.
+    ///
+    /// # Errors
+    ///
+    /// Returns why the guess would be rejected.
+    pub fn check(&self, guess: &str, word_list: &HashSet<String>) -> Result<(), GuessError> {
+        let guess = guess.to_uppercase();
+
+        if self.status() != GameStatus::InProgress {
+            return Err(GuessError::GameOver);
+        }
+        if guess.chars().count() != self.word_length() {
+            return Err(GuessError::NotEnoughLetters);
+        }
+        if !word_list.contains(&guess) {
+            return Err(GuessError::NotInWordList);
+        }
+        if self.hard_mode && !self.uses_revealed_hints(&guess) {
+            return Err(GuessError::MissingHints);
+        }
+
+        Ok(())
+    }
+
+    /// Greens must stay in place and every revealed letter must be reused, at
+    /// least as many times as it was revealed in a single guess.
+    #[must_use]
+    pub fn uses_revealed_hints(&self, guess: &str) -> bool {
+        self.guesses.iter().all(|previous| {
+            let greens_kept = previous
+                .letters()
+                .enumerate()
+                .filter(|&(_, (_, state))| state == LetterState::Correct)
+                .all(|(index, (letter, _))| guess.chars().nth(index) == Some(letter));
+
+            let revealed_reused = previous
+                .letters()
+                .filter(|&(_, state)| state != LetterState::Absent)
+                .all(|(letter, _)| {
+                    let revealed = previous
+                        .letters()
+                        .filter(|&(c, state)| c == letter && state != LetterState::Absent)
+                        .count();
+                    guess.chars().filter(|&c| c == letter).count() >= revealed
+                });
+
+            greens_kept && revealed_reused
+        })
+    }
+
+    /// The hint to give after `given`, picked with `rng` out of what the
+    /// guesses and earlier hints haven't already given away. Ruling out
+    /// letters from `alphabet` and revealing a letter take turns, starting
+    /// with ruling out, and whichever has nothing left gives way to the other.
+    /// `None` once neither does.
+    pub fn hint(&self, given: &[Hint], alphabet: &HashSet<char>, rng: &mut Rng) -> Option<Hint> {
+        let revealed = given
+            .iter()
+            .filter(|hint| matches!(hint, Hint::Letter { .. }))
+            .count();
+        let absent = self.hint_absent(given, alphabet, rng);
+        let letter = self.hint_letter(given, rng);
+
+        if revealed < given.len() - revealed {
+            letter.or(absent)
+        } else {
+            absent.or(letter)
+        }
+    }
+
+    fn hint_letter(&self, given: &[Hint], rng: &mut Rng) -> Option<Hint> {
+        let hidden = self
+            .solution
+            .chars()
+            .enumerate()
+            .filter(|&(index, letter)| {
+                !self
+                    .guesses
+                    .iter()
+                    .any(|guess| guess.word.chars().nth(index) == Some(letter))
+                    && !given.contains(&Hint::Letter { index, letter })
+            })
+            .collect::<Vec<(usize, char)>>();
+        let &(index, letter) = hidden.get(rng.usize(..hidden.len().max(1)))?;
+
+        Some(Hint::Letter { index, letter })
+    }
+
+    fn hint_absent(&self, given: &[Hint], alphabet: &HashSet<char>, rng: &mut Rng) -> Option<Hint> {
+        let mut unknown = alphabet
+            .iter()
+            .copied()
+            .filter(|&letter| {
+                !self.solution.contains(letter)
+                    && !self.guesses.iter().any(|guess| guess.word.contains(letter))
+                    && !given.iter().any(
+                        |hint| matches!(hint, Hint::Absent(letters) if letters.contains(&letter)),
+                    )
+            })
+            .collect::<Vec<char>>();
+        if unknown.is_empty() {
+            return None;
+        }
+
+        // sorted first so the same rng always picks the same letters
+        unknown.sort_unstable();
+        rng.shuffle(&mut unknown);
+        unknown.truncate(ABSENT_PER_HINT);
+        unknown.sort_unstable();
+
+        Some(Hint::Absent(unknown))
+    }
+}
+
+/// The word `guess` looks like a typo of, if any of `words` is one.
+///
+/// A typo is two neighbouring letters swapped or a single letter wrong. Swaps
+/// are the likelier slip so they win over a wrong letter, after that it's
+/// alphabetical.
+pub fn suggest<'a>(guess: &str, words: impl IntoIterator<Item = &'a String>) -> Option<&'a str> {
+    let guess = guess.to_uppercase().chars().collect::<Vec<char>>();
+
+    words
+        .into_iter()
+        .filter_map(|word| {
+            let letters = word.chars().collect::<Vec<char>>();
+            if letters.len() != guess.len() {
+                return None;
+            }
+
+            let differing = (0..guess.len())
+                .filter(|&i| letters[i] != guess[i])
+                .collect::<Vec<usize>>();
+            let rank = match differing[..] {
+                [a, b] if b == a + 1 && letters[a] == guess[b] && letters[b] == guess[a] => 0,
+                [_] => 1,
+                _ => return None,
+            };
+            Some((rank, word))
+        })
+        .min()
+        .map(|(_, word)| word.as_str())
+}
+
+/// How many letters a hint rules out at once.
+pub const ABSENT_PER_HINT: usize = 3;
+
+/// Something given away without spending a guess, for anyone who's stuck.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Hint {
+    /// The solution has `letter` at `index`.
+    Letter { index: usize, letter: char },
+    /// None of these letters are in the solution.
+    Absent(Vec<char>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use LetterState::{Absent as A, Correct as C, Present as P};
+
+    const WORDS: &str = include_str!("words.txt");
+
+    fn word_list(words: &[&str]) -> HashSet<String> {
+        words.iter().map(|w| w.to_uppercase()).collect()
+    }
+
+    #[test]
+    fn scores_exact_and_missing_letters() {
+        assert_eq!(score_guess("CRANE", "CRANE"), [C; WORD_LENGTH]);
+        assert_eq!(score_guess("CRANE", "FJORD"), [A, A, A, P, A]);
+        assert_eq!(score_guess("CRANE", "BUMPY"), [A; WORD_LENGTH]);
+    }
+
+    #[test]
+    fn yellows_run_out_with_the_solution_letters() {
+        assert_eq!(score_guess("ERASE", "SPEED"), [P, A, P, P, A]);
+        assert_eq!(score_guess("SPEED", "ERASE"), [P, A, A, P, P]);
+        assert_eq!(score_guess("ERASE", "EEEEE"), [C, A, A, A, C]);
+    }
+
+    #[test]
+    fn greens_claim_letters_before_earlier_yellows() {
+        assert_eq!(score_guess("CRANE", "EERIE"), [A, A, P, A, C]);
+        assert_eq!(score_guess("WORLD", "LOLLY"), [A, C, A, C, A]);
+        assert_eq!(score_guess("ABBEY", "KEBAB"), [A, P, C, P, P]);
+        assert_eq!(score_guess("ABBEY", "BABES"), [P, P, C, C, A]);
+    }
+
+    // every letter is marked at most as many times as the solution has it,
+    // and greens are exactly the matching positions
+    #[test]
+    fn scoring_invariants_hold_for_random_words() {
+        let words = WORDS
+            .lines()
+            .map(str::to_uppercase)
+            .collect::<Vec<String>>();
+        let mut rng = fastrand::Rng::with_seed(0x5EED);
+
+        for _ in 0..10_000 {
+            let solution = &words[rng.usize(..words.len())];
+            let guess = &words[rng.usize(..words.len())];
+            let states = score_guess(solution, guess);
+
+            for (index, (s, g)) in solution.chars().zip(guess.chars()).enumerate() {
+                assert_eq!(states[index] == C, s == g, "{guess} against {solution}");
+            }
+
+            for letter in guess.chars() {
+                let marked = guess
+                    .chars()
+                    .zip(states.iter().copied())
+                    .filter(|&(c, state)| c == letter && state != A)
+                    .count();
+                let expected = guess
+                    .matches(letter)
+                    .count()
+                    .min(solution.matches(letter).count());
+                assert_eq!(marked, expected, "{letter} in {guess} against {solution}");
+            }
+        }
+    }
+
+    #[test]
+    fn longer_words_are_scored_and_checked_by_their_own_length() {
+        assert_eq!(score_guess("PLANET", "PLANTS"), [C, C, C, C, P, A]);
+
+        let words = word_list(&["planet", "crane"]);
+        let mut game = Game::new("planet", false);
+        assert_eq!(game.word_length(), 6);
+        assert_eq!(
+            game.submit("crane", &words),
+            Err(GuessError::NotEnoughLetters)
+        );
+        assert!(game.submit("planet", &words).is_ok());
+    }
+
+    #[test]
+    fn game_ends_on_a_win_or_after_the_last_guess() {
+        let words = word_list(&["crane", "fjord", "bumpy"]);
+
+        let mut game = Game::new("crane", false);
+        assert_eq!(
+            game.submit("fjord", &words).map(|g| g.states.clone()),
+            Ok(vec![A, A, A, P, A])
+        );
+        assert_eq!(
+            game.submit("crane", &words).map(Guess::is_correct),
+            Ok(true)
+        );
+        assert_eq!(game.status(), GameStatus::Won);
+        assert_eq!(game.submit("bumpy", &words), Err(GuessError::GameOver));
+
+        let mut game = Game::new("crane", false);
+        for _ in 0..MAX_GUESSES {
+            assert_eq!(game.status(), GameStatus::InProgress);
+            assert!(game.submit("bumpy", &words).is_ok());
+        }
+        assert_eq!(game.status(), GameStatus::Lost);
+    }
+
+    #[test]
+    fn typos_are_matched_to_words() {
+        let words = word_list(&["crane", "crank", "brine"]);
+
+        assert_eq!(suggest("crnae", &words), Some("CRANE"));
+        assert_eq!(suggest("cranx", &words), Some("CRANE"));
+        assert_eq!(suggest("brinq", &words), Some("BRINE"));
+        assert_eq!(suggest("zzzzz", &words), None);
+        assert_eq!(suggest("cranes", &words), None);
+    }
+
+    #[test]
+    fn max_guesses_can_be_raised() {
+        let words = word_list(&["crane", "bumpy"]);
+        let mut game = Game::new("crane", false).with_max_guesses(MAX_GUESSES + 3);
+
+        for _ in 0..MAX_GUESSES {
+            assert!(game.submit("bumpy", &words).is_ok());
+        }
+        assert_eq!(game.status(), GameStatus::InProgress);
+        assert!(game.submit("crane", &words).is_ok());
+        assert_eq!(game.status(), GameStatus::Won);
+    }
+
+    #[test]
+    fn rejected_guesses_are_not_recorded() {
+        let words = word_list(&["crane"]);
+        let mut game = Game::new("crane", false);
+
+        assert_eq!(
+            game.submit("cran", &words),
+            Err(GuessError::NotEnoughLetters)
+        );
+        assert_eq!(game.submit("zzzzz", &words), Err(GuessError::NotInWordList));
+        assert_eq!(game.check("crane", &words), Ok(()));
+        assert!(game.guesses().is_empty());
+    }
+
+    #[test]
+    fn hard_mode_requires_revealed_hints() {
+        let words = word_list(&["crane", "crone", "trace", "bumpy"]);
+        let mut game = Game::new("crane", true);
+
+        assert!(game.submit("trace", &words).is_ok());
+        // R and A are green, C and E are yellow
+        assert_eq!(game.submit("bumpy", &words), Err(GuessError::MissingHints));
+        assert_eq!(game.submit("crone", &words), Err(GuessError::MissingHints));
+        assert!(game.submit("crane", &words).is_ok());
+        assert!(!game.set_hard_mode(false));
+    }
+
+    #[test]
+    fn forfeited_rows_use_a_guess_and_reveal_nothing() {
+        let words = word_list(&["crane", "trace"]);
+        let mut game = Game::new("crane", true);
+
+        let guess = game.forfeit().cloned();
+        assert_eq!(
+            guess.map(|g| (g.is_forfeited(), g.states)),
+            Ok((true, vec![A; WORD_LENGTH]))
+        );
+        // nothing was revealed, so hard mode has nothing to hold the next guess to
+        assert!(game.submit("trace", &words).is_ok());
+
+        let words = game
+            .guesses()
+            .iter()
+            .map(|g| g.word.clone())
+            .collect::<Vec<String>>();
+        let restored = Game::restore("crane", true, MAX_GUESSES, &words);
+        assert!(restored.guesses()[0].is_forfeited());
+        assert_eq!(restored.guesses()[1].states, game.guesses()[1].states);
+    }
+
+    #[test]
+    fn evading_keeps_the_most_words_alive_until_it_cant() {
+        let answers = word_list(&["crane", "bumpy", "dumpy", "lumpy"]);
+        let mut game = Game::new("crane", false);
+
+        // the three -umpy words all come back gray, crane is alone in its group
+        let guess = game.evade("crane", &answers, &answers).cloned();
+        assert_eq!(guess.map(|g| g.states), Ok(vec![A; WORD_LENGTH]));
+        assert_ne!(game.solution(), "CRANE");
+
+        assert!(!game
+            .evade("dumpy", &answers, &answers)
+            .unwrap()
+            .is_correct());
+        // bumpy and lumpy are one each now, and bumpy gives less away
+        assert!(!game
+            .evade("lumpy", &answers, &answers)
+            .unwrap()
+            .is_correct());
+        assert_eq!(game.solution(), "BUMPY");
+        assert!(game
+            .evade("bumpy", &answers, &answers)
+            .unwrap()
+            .is_correct());
+    }
+
+    #[test]
+    fn hints_take_turns_and_skip_what_is_known() {
+        let words = word_list(&["crane", "crone"]);
+        let alphabet = ('A'..='Z').collect::<HashSet<char>>();
+        let mut game = Game::new("crane", false);
+        assert!(game.submit("crone", &words).is_ok());
+        let mut rng = Rng::with_seed(7);
+
+        let mut given = Vec::new();
+        let first = game.hint(&given, &alphabet, &mut rng);
+        let Some(Hint::Absent(letters)) = &first else {
+            panic!("expected letters ruled out first, got {first:?}");
+        };
+        assert_eq!(letters.len(), ABSENT_PER_HINT);
+        assert!(letters.iter().all(|&letter| !"CRANEO".contains(letter)));
+        given.extend(first);
+
+        // only the A is still hidden
+        let second = game.hint(&given, &alphabet, &mut rng);
+        assert_eq!(
+            second,
+            Some(Hint::Letter {
+                index: 2,
+                letter: 'A'
+            })
+        );
+        given.extend(second);
+
+        assert!(matches!(
+            game.hint(&given, &alphabet, &mut rng),
+            Some(Hint::Absent(_))
+        ));
+        given.extend(game.hint(&given, &alphabet, &mut rng));
+        // nothing left to reveal, so it's letters ruled out from here on
+        assert!(matches!(
+            game.hint(&given, &alphabet, &mut rng),
+            Some(Hint::Absent(_))
+        ));
+    }
+
+    #[test]
+    fn restored_games_are_scored_again() {
+        let game = Game::restore(
+            "crane",
+            true,
+            MAX_GUESSES,
+            &[String::from("fjord"), String::from("crane")],
+        );
+
+        assert!(game.hard_mode());
+        assert_eq!(game.guesses().len(), 2);
+        assert_eq!(game.guesses()[0].states, [A, A, A, P, A]);
+        assert_eq!(game.status(), GameStatus::Won);
+    }
+
+    #[test]
+    fn restored_games_keep_their_guess_limit() {
+        let mut words = vec![String::from("bumpy"); MAX_GUESSES + 1];
+        words.push(String::from("crane"));
+        let game = Game::restore("crane", false, MAX_GUESSES + 2, &words);
+
+        assert_eq!(game.max_guesses(), MAX_GUESSES + 2);
+        assert_eq!(game.guesses().len(), MAX_GUESSES + 2);
+        assert_eq!(game.status(), GameStatus::Won);
+    }
+}